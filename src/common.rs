@@ -6,9 +6,13 @@ extern crate self as xrb;
 
 use array_init::array_init;
 use derive_more::{From, Into};
+use std::{
+	net::{IpAddr, Ipv4Addr, Ipv6Addr},
+	time::Duration,
+};
 use thiserror::Error;
 
-pub use atom::Atom;
+pub use atom::{Atom, NonZeroAtom};
 pub use mask::*;
 pub use res_id::*;
 pub use wrapper::*;
@@ -29,7 +33,7 @@ use xrbk::{
 };
 use xrbk_macro::{derive_xrb, new, unwrap, ConstantX11Size, Readable, Wrap, Writable, X11Size};
 
-use crate::unit::Px;
+use crate::unit::{Angle, Px};
 
 pub mod atom;
 pub mod set;
@@ -48,8 +52,24 @@ pub enum Toggle {
 	Enabled,
 }
 
+impl From<bool> for Toggle {
+	fn from(enabled: bool) -> Self {
+		if enabled {
+			Self::Enabled
+		} else {
+			Self::Disabled
+		}
+	}
+}
+
+impl From<Toggle> for bool {
+	fn from(toggle: Toggle) -> Self {
+		matches!(toggle, Toggle::Enabled)
+	}
+}
+
 /// Whether something is enabled, disabled, or the default is chosen.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, X11Size, ConstantX11Size, Readable, Writable)]
 pub enum ToggleOrDefault {
 	/// The thing is disabled.
 	Disabled,
@@ -88,10 +108,58 @@ pub enum ToggleOrDefault {
 )]
 pub struct Timestamp(pub(crate) u32);
 
+impl Timestamp {
+	/// Half of the range of `Timestamp`s.
+	///
+	/// Any `Timestamp` less than this distance after another is considered
+	/// later than that `Timestamp`; any `Timestamp` more than this distance
+	/// after another has wrapped around, and so is considered earlier.
+	const HALF_RANGE: u32 = 1 << 31;
+
+	/// Returns the [`Duration`] that has elapsed between `earlier` and `self`.
+	///
+	/// This takes into account wrapping around back to 0: half of the
+	/// `Timestamp` range which follows `earlier` is considered later, and the
+	/// other half is considered earlier.
+	///
+	/// Returns [`None`] if `earlier` is actually later than `self`.
+	#[must_use]
+	pub const fn checked_duration_since(&self, earlier: Self) -> Option<Duration> {
+		let elapsed = self.0.wrapping_sub(earlier.0);
+
+		if elapsed < Self::HALF_RANGE {
+			Some(Duration::from_millis(elapsed as u64))
+		} else {
+			None
+		}
+	}
+
+	/// Returns whether `self` is later than `other`, taking into account
+	/// wrapping around back to 0.
+	///
+	/// See [`checked_duration_since`] for more information.
+	///
+	/// [`checked_duration_since`]: Timestamp::checked_duration_since
+	#[must_use]
+	pub const fn is_later_than(&self, other: Self) -> bool {
+		self.0 != other.0 && self.checked_duration_since(other).is_some()
+	}
+
+	/// Returns whether `self` is earlier than `other`, taking into account
+	/// wrapping around back to 0.
+	///
+	/// See [`checked_duration_since`] for more information.
+	///
+	/// [`checked_duration_since`]: Timestamp::checked_duration_since
+	#[must_use]
+	pub const fn is_earlier_than(&self, other: Self) -> bool {
+		other.is_later_than(*self)
+	}
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, X11Size, Readable, Writable)]
 pub enum BitGravity {
 	Forget,
-	Static,
 	NorthWest,
 	North,
 	NorthEast,
@@ -101,12 +169,12 @@ pub enum BitGravity {
 	SouthWest,
 	South,
 	SouthEast,
+	Static,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, X11Size, Readable, Writable)]
 pub enum WindowGravity {
 	Unmap,
-	Static,
 	NorthWest,
 	North,
 	NorthEast,
@@ -116,6 +184,7 @@ pub enum WindowGravity {
 	SouthWest,
 	South,
 	SouthEast,
+	Static,
 }
 
 // The `derive_xrb!` attribute here is used to write the discriminants as `u16`.
@@ -184,19 +253,19 @@ pub enum GrabMode {
 /// Whether a grab causes a freeze in [event] processing.
 ///
 /// [event]: crate::message::Event
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, X11Size, ConstantX11Size, Readable, Writable)]
 pub enum FreezeMode {
-	/// [Event] processing is not frozen.
-	///
-	/// [Event]: crate::message::Event
-	#[doc(alias = "Asynchronous")]
-	Unfrozen,
-
 	/// [Event] processing is frozen.
 	///
 	/// [Event]: crate::message::Event
 	#[doc(alias = "Synchronous")]
 	Frozen,
+
+	/// [Event] processing is not frozen.
+	///
+	/// [Event]: crate::message::Event
+	#[doc(alias = "Asynchronous")]
+	Unfrozen,
 }
 
 /// The status of an attempted grab.
@@ -341,6 +410,12 @@ impl Button {
 )]
 pub struct Char8(pub(crate) u8);
 
+/// The string used to create a [`String8`] contained a character outside of
+/// ISO Latin-1.
+#[derive(Error, Debug)]
+#[error("the provided string contained a character outside of ISO Latin-1")]
+pub struct NonLatin1Character;
+
 #[derive(Clone, Eq, PartialEq, Hash, Debug, From, Into, X11Size, Writable)]
 pub struct String8(Vec<Char8>);
 
@@ -354,6 +429,30 @@ impl String8 {
 	pub fn is_empty(&self) -> bool {
 		self.0.is_empty()
 	}
+
+	/// Returns an iterator over the [`char`]s represented by this string.
+	///
+	/// Each [`Char8`] is interpreted as an ISO Latin-1 character, which maps
+	/// directly to the Unicode code point of the same value.
+	pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+		self.0.iter().map(|Char8(byte)| char::from(*byte))
+	}
+}
+
+impl TryFrom<&str> for String8 {
+	type Error = NonLatin1Character;
+
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		string
+			.chars()
+			.map(|char| {
+				u8::try_from(char)
+					.map(Char8::new)
+					.map_err(|_| NonLatin1Character)
+			})
+			.collect::<Result<_, _>>()
+			.map(Self)
+	}
 }
 
 impl ReadableWithContext for String8 {
@@ -390,6 +489,14 @@ derive_xrb! {
 	}
 }
 
+impl LengthString8 {
+	/// Returns the wrapped [`String8`].
+	#[must_use]
+	pub const fn string(&self) -> &String8 {
+		&self.string
+	}
+}
+
 #[derive(
 	Copy,
 	Clone,
@@ -426,6 +533,17 @@ impl From<Char16> for u16 {
 	}
 }
 
+/// The string used to create a [`String16`] contained a character outside of
+/// the Basic Multilingual Plane.
+#[derive(Error, Debug)]
+#[error("the provided string contained a character outside of the Basic Multilingual Plane")]
+pub struct NonBmpCharacter;
+
+/// A string of [`Char16`]s.
+///
+/// Each [`Char16`] is encoded as two bytes, the most significant byte first.
+/// When used as text, each [`Char16`] is interpreted as a UCS-2 code point:
+/// that is, a character in the Basic Multilingual Plane.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, From, Into, X11Size, Writable)]
 pub struct String16(Vec<Char16>);
 
@@ -439,6 +557,33 @@ impl String16 {
 	pub fn is_empty(&self) -> bool {
 		self.0.is_empty()
 	}
+
+	/// Returns an iterator over the [`char`]s represented by this string.
+	///
+	/// Each [`Char16`] is interpreted as a UCS-2 code point. Code points which
+	/// are not valid [`char`]s (i.e. surrogates) are replaced with
+	/// [`char::REPLACEMENT_CHARACTER`].
+	pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+		self.0.iter().map(|char| {
+			char::from_u32(u16::from(*char).into()).unwrap_or(char::REPLACEMENT_CHARACTER)
+		})
+	}
+}
+
+impl TryFrom<&str> for String16 {
+	type Error = NonBmpCharacter;
+
+	fn try_from(string: &str) -> Result<Self, Self::Error> {
+		string
+			.chars()
+			.map(|char| {
+				u16::try_from(u32::from(char))
+					.map(Char16::from)
+					.map_err(|_| NonBmpCharacter)
+			})
+			.collect::<Result<_, _>>()
+			.map(Self)
+	}
 }
 
 impl ReadableWithContext for String16 {
@@ -562,16 +707,14 @@ pub struct Arc {
 
 	/// Specifies the start of the `Arc`.
 	///
-	/// The angle is measured in degrees scaled by 64. Positive indicates
-	/// counterclockwise motion and negative indicates clockwise motion.
-	/// The angle is measured relative to the three-o'clock position from
-	/// the center of the rectangle.
-	pub start_angle: i16,
+	/// Positive indicates counterclockwise motion and negative indicates
+	/// clockwise motion. The angle is measured relative to the three-o'clock
+	/// position from the center of the rectangle.
+	pub start_angle: Angle,
 	/// Specifies the extent of the `Arc` relative to the `start_angle`.
 	///
-	/// The angle is measured in degrees scaled by 64. If greater than 360
-	/// degrees, this angle is truncated to 360 degrees.
-	pub end_angle: i16,
+	/// If greater than 360 degrees, this angle is truncated to 360 degrees.
+	pub end_angle: Angle,
 }
 
 /// The address family of a host.
@@ -701,6 +844,42 @@ impl HostAddress {
 			Self::Ipv6(..) => HostFamily::Ipv6,
 		}
 	}
+
+	/// Returns the IP address represented by this address, if it is an
+	/// [IPv4] or [IPv6] address.
+	///
+	/// [IPv4]: HostAddress::Ipv4
+	/// [IPv6]: HostAddress::Ipv6
+	#[must_use]
+	pub fn ip_addr(&self) -> Option<IpAddr> {
+		match self {
+			Self::Ipv4(octets) => Some(Ipv4Addr::from(*octets).into()),
+			Self::Ipv6(octets) => Some(Ipv6Addr::from(*octets).into()),
+
+			_ => None,
+		}
+	}
+}
+
+impl From<Ipv4Addr> for HostAddress {
+	fn from(address: Ipv4Addr) -> Self {
+		Self::Ipv4(address.octets())
+	}
+}
+
+impl From<Ipv6Addr> for HostAddress {
+	fn from(address: Ipv6Addr) -> Self {
+		Self::Ipv6(address.octets())
+	}
+}
+
+impl From<IpAddr> for HostAddress {
+	fn from(address: IpAddr) -> Self {
+		match address {
+			IpAddr::V4(address) => address.into(),
+			IpAddr::V6(address) => address.into(),
+		}
+	}
 }
 
 impl X11Size for HostAddress {
@@ -748,7 +927,6 @@ impl ReadableWithContext for HostAddress {
 				while buf.has_remaining() {
 					match buf.get_u8() {
 						0 => {
-							buf.advance(1);
 							address_value = <Vec<u8>>::read_with(buf, &buf.remaining())?;
 
 							break;
@@ -822,3 +1000,153 @@ derive_xrb! {
 		[_; address => pad(address)],
 	}
 }
+
+impl From<IpAddr> for Host {
+	fn from(address: IpAddr) -> Self {
+		Self::new(address.into())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_write_rectangle_list() {
+		let rectangles = vec![
+			Rectangle::new(Px(1), Px(2), Px(3), Px(4)),
+			Rectangle::new(Px(-1), Px(-2), Px(30), Px(40)),
+		];
+
+		let bytes = write(&rectangles);
+
+		// Each rectangle is written consecutively, with no length prefix.
+		assert_eq!(rectangles.x11_size(), 2 * Rectangle::X11_SIZE);
+		assert_eq!(
+			bytes,
+			[
+				0, 1, 0, 2, 0, 3, 0, 4, // first
+				0xff, 0xff, 0xff, 0xfe, 0, 30, 0, 40, // second
+			],
+		);
+
+		let slice_bytes = write(&rectangles[..]);
+
+		assert_eq!(rectangles[..].x11_size(), rectangles.x11_size());
+		assert_eq!(slice_bytes, bytes);
+	}
+
+	#[test]
+	fn test_arc_round_trip() {
+		let arc = Arc::new(
+			Rectangle::new(Px(-10), Px(20), Px(100), Px(50)),
+			Angle::from_degrees(90.0),
+			Angle::from_degrees(-180.0),
+		);
+
+		assert_roundtrip!(arc);
+
+		let bytes = write(&arc);
+
+		assert_eq!(&bytes[8..], [0x16, 0x80, 0xd3, 0x00]);
+	}
+
+	#[test]
+	fn test_host_ipv4() {
+		let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1));
+		let host = Host::from(ip);
+
+		assert_eq!(host.address, HostAddress::Ipv4([192, 168, 0, 1]));
+		assert_eq!(host.address.family(), HostFamily::Ipv4);
+		assert_eq!(host.address.ip_addr(), Some(ip));
+
+		assert_roundtrip!(host);
+	}
+
+	#[test]
+	fn test_host_ipv6() {
+		let ip = IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+		let host = Host::from(ip);
+
+		assert_eq!(host.address.family(), HostFamily::Ipv6);
+		assert_eq!(host.address.ip_addr(), Some(ip));
+
+		assert_roundtrip!(host);
+	}
+
+	#[test]
+	fn test_host_server_interpreted() {
+		let host = Host::new(HostAddress::ServerInterpreted {
+			address_type: AsciiString::new(b"localuser".to_vec()).unwrap(),
+			address_value: AsciiString::new(b"root".to_vec()).unwrap(),
+		});
+
+		assert_eq!(host.address.ip_addr(), None);
+		assert_roundtrip!(host);
+	}
+
+	#[test]
+	fn test_timestamp_duration_since() {
+		let earlier = Timestamp::new(1000);
+		let later = Timestamp::new(1500);
+
+		assert_eq!(
+			later.checked_duration_since(earlier),
+			Some(Duration::from_millis(500))
+		);
+		assert_eq!(earlier.checked_duration_since(later), None);
+		assert!(later.is_later_than(earlier));
+		assert!(earlier.is_earlier_than(later));
+		assert!(!earlier.is_later_than(earlier));
+	}
+
+	#[test]
+	fn test_timestamp_wrap() {
+		let before_wrap = Timestamp::new(u32::MAX - 99);
+		let after_wrap = Timestamp::new(400);
+
+		assert_eq!(
+			after_wrap.checked_duration_since(before_wrap),
+			Some(Duration::from_millis(500))
+		);
+		assert_eq!(before_wrap.checked_duration_since(after_wrap), None);
+		assert!(after_wrap.is_later_than(before_wrap));
+		assert!(before_wrap.is_earlier_than(after_wrap));
+	}
+
+	#[test]
+	fn test_string8_from_str() {
+		let string = String8::try_from("héllo").unwrap();
+
+		let bytes = write(&string);
+
+		// Each character is encoded as its ISO Latin-1 byte, not UTF-8.
+		assert_eq!(bytes, [b'h', 0xe9, b'l', b'l', b'o']);
+		assert_eq!(string.chars().collect::<String>(), "héllo");
+
+		assert!(String8::try_from("✓").is_err());
+	}
+
+	#[test]
+	fn test_string16_round_trip() {
+		let string = String16::try_from("héllo ✓").unwrap();
+
+		let bytes = write(&string);
+
+		// The most significant byte of each character comes first.
+		assert_eq!(&bytes[2..4], &[0x00, 0xe9]);
+		assert_eq!(&bytes[12..14], &[0x27, 0x13]);
+
+		let read = String16::read_with(&mut &bytes[..], &string.len()).unwrap();
+
+		assert_eq!(read, string);
+		assert_eq!(read.chars().collect::<String>(), "héllo ✓");
+	}
+
+	#[test]
+	fn test_string16_non_bmp() {
+		assert!(String16::try_from("🦀").is_err());
+	}
+}
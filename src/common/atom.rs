@@ -5,11 +5,36 @@
 //! [`Atom`] and predefined atom `const`s defined in the core protocol.
 
 use derive_more::{From, Into};
+use std::{collections::HashMap, num::NonZeroU32};
+use xrbk::{
+	Buf,
+	BufMut,
+	ConstantX11Size,
+	ReadError,
+	ReadResult,
+	Readable,
+	Writable,
+	WriteResult,
+	X11Size,
+};
 use xrbk_macro::{ConstantX11Size, Readable, Wrap, Writable, X11Size};
 
+use crate::{
+	x11::{reply, request::GetAtom},
+	String8,
+};
+
 /// A unique ID corresponding to a string name.
 ///
 /// `Atom`s are used to identify properties, types, and selections.
+///
+/// The value `0` does not refer to any `Atom`: where `0` has a special meaning
+/// in the X11 protocol (typically 'none'), <code>[Option]<Atom></code> or
+/// another wrapper such as [`Specificity`] is used instead, and where an `Atom`
+/// must be given, [`NonZeroAtom`] is used. An `Atom` of `0` may still be read
+/// and written, as servers do send `0` in `Atom` fields.
+///
+/// [`Specificity`]: crate::Specificity
 #[derive(
 	Copy,
 	Clone,
@@ -40,6 +65,84 @@ impl Atom {
 	pub const fn unwrap(self) -> u32 {
 		self.0
 	}
+
+	/// Whether this `Atom`'s `id` is `0`, and so does not refer to any `Atom`.
+	///
+	/// An `Atom` of `0` is represented by [`None`] in an
+	/// <code>[Option]<Atom></code>.
+	#[must_use]
+	pub const fn is_none(self) -> bool {
+		self.0 == 0
+	}
+
+	/// Creates a [`NonZeroAtom`] with the given `id`.
+	///
+	/// Returns [`None`] if `id` is zero, as zero does not refer to any `Atom`.
+	#[must_use]
+	pub const fn new_nonzero(id: u32) -> Option<NonZeroAtom> {
+		match NonZeroU32::new(id) {
+			Some(id) => Some(NonZeroAtom(id)),
+			None => None,
+		}
+	}
+}
+
+/// An [`Atom`] which is known not to be zero.
+///
+/// An `Atom` of zero does not refer to any `Atom`. A `NonZeroAtom` is used
+/// where an `Atom` must be given, such as the selection in a
+/// [`SetSelectionOwner` request]: an `Atom` of zero is rejected when it is
+/// read, rather than being sent to the X server.
+///
+/// [`SetSelectionOwner` request]: crate::x11::request::SetSelectionOwner
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NonZeroAtom(NonZeroU32);
+
+impl NonZeroAtom {
+	/// Returns this `NonZeroAtom` as an [`Atom`].
+	#[must_use]
+	pub const fn get(self) -> Atom {
+		Atom(self.0.get())
+	}
+}
+
+impl From<NonZeroAtom> for Atom {
+	fn from(atom: NonZeroAtom) -> Self {
+		atom.get()
+	}
+}
+
+impl TryFrom<Atom> for NonZeroAtom {
+	type Error = Atom;
+
+	fn try_from(atom: Atom) -> Result<Self, Self::Error> {
+		Atom::new_nonzero(atom.0).ok_or(atom)
+	}
+}
+
+impl ConstantX11Size for NonZeroAtom {
+	const X11_SIZE: usize = 4;
+}
+
+impl X11Size for NonZeroAtom {
+	fn x11_size(&self) -> usize {
+		Self::X11_SIZE
+	}
+}
+
+impl Readable for NonZeroAtom {
+	fn read_from(buf: &mut impl Buf) -> ReadResult<Self> {
+		Atom::new_nonzero(buf.get_u32())
+			.ok_or_else(|| ReadError::Other(Box::new("unexpected zero atom")))
+	}
+}
+
+impl Writable for NonZeroAtom {
+	fn write_to(&self, buf: &mut impl BufMut) -> WriteResult {
+		buf.put_u32(self.0.get());
+
+		Ok(())
+	}
 }
 
 macro_rules! atoms {
@@ -126,3 +229,158 @@ atoms! {
 	WM_CLASS = 67,
 	WM_TRANSIENT_FOR = 68,
 }
+
+/// A cache of [`Atom`]s by name.
+///
+/// Clients, and window managers especially, frequently need the same
+/// [`Atom`]s. Caching them means a [`GetAtom` request] need only be sent once
+/// for each name.
+///
+/// [`GetAtom` request]: GetAtom
+#[doc(alias("InternAtomCache"))]
+#[derive(Clone, Debug, Default)]
+pub struct AtomCache {
+	atoms: HashMap<String8, Atom>,
+}
+
+impl AtomCache {
+	/// Creates a new, empty `AtomCache`.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the cached [`Atom`] with the given `name`, if there is one.
+	#[must_use]
+	pub fn get(&self, name: &String8) -> Option<Atom> {
+		self.atoms.get(name).copied()
+	}
+
+	/// Returns the cached [`Atom`] with the given `name`, or the
+	/// [`GetAtom` request] which must be sent to get it if it is not cached.
+	///
+	/// The [`GetAtom` reply] can then be cached with [`cache_reply`].
+	///
+	/// # Errors
+	/// Returns the [`GetAtom` request] for the given `name` and `no_creation`
+	/// if the [`Atom`] is not cached.
+	///
+	/// [`GetAtom` request]: GetAtom
+	/// [`GetAtom` reply]: reply::GetAtom
+	/// [`cache_reply`]: AtomCache::cache_reply
+	pub fn get_or_request(&self, name: String8, no_creation: bool) -> Result<Atom, GetAtom> {
+		self.get(&name).ok_or(GetAtom { no_creation, name })
+	}
+
+	/// Caches the [`Atom`] returned in the `reply` to the given `request`.
+	///
+	/// If the `reply` contains no [`Atom`], because the `request` had
+	/// `no_creation` set and no [`Atom`] exists with that name, nothing is
+	/// cached.
+	///
+	/// Returns the [`Atom`] from the `reply`.
+	pub fn cache_reply(&mut self, request: GetAtom, reply: &reply::GetAtom) -> Option<Atom> {
+		if let Some(atom) = reply.atom {
+			self.atoms.insert(request.name, atom);
+		}
+
+		reply.atom
+	}
+
+	/// Caches the given [`Atom`] with the given `name`.
+	///
+	/// Returns the [`Atom`] which was previously cached with that `name`, if
+	/// any.
+	pub fn insert(&mut self, name: String8, atom: Atom) -> Option<Atom> {
+		self.atoms.insert(name, atom)
+	}
+
+	/// Returns the number of cached [`Atom`]s.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.atoms.len()
+	}
+
+	/// Returns whether no [`Atom`]s are cached.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.atoms.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_atom_cache() {
+		let mut cache = AtomCache::new();
+		let name = String8::try_from("WM_PROTOCOLS").unwrap();
+
+		let request = cache.get_or_request(name.clone(), true).unwrap_err();
+		assert!(request.no_creation);
+		assert_eq!(request.name, name);
+
+		let reply = reply::GetAtom {
+			sequence: 1,
+			atom: Some(Atom::new(300)),
+		};
+		assert_eq!(cache.cache_reply(request, &reply), Some(Atom::new(300)));
+
+		assert_eq!(cache.len(), 1);
+		assert_eq!(cache.get_or_request(name, true).unwrap(), Atom::new(300));
+	}
+
+	#[test]
+	fn test_atom_cache_no_atom() {
+		let mut cache = AtomCache::new();
+
+		let request = cache
+			.get_or_request(String8::try_from("_NET_WM_NAME").unwrap(), true)
+			.unwrap_err();
+		let reply = reply::GetAtom {
+			sequence: 1,
+			atom: None,
+		};
+
+		assert_eq!(cache.cache_reply(request, &reply), None);
+		assert!(cache.is_empty());
+	}
+
+	#[test]
+	fn test_atom_is_none() {
+		assert!(Atom::new(0).is_none());
+		assert!(!PRIMARY.is_none());
+	}
+
+	#[test]
+	fn test_nonzero_atom() {
+		assert_eq!(Atom::new_nonzero(0), None);
+		assert_eq!(NonZeroAtom::try_from(Atom::new(0)), Err(Atom::new(0)));
+		assert_eq!(
+			NonZeroAtom::try_from(PRIMARY).map(NonZeroAtom::get),
+			Ok(PRIMARY)
+		);
+
+		let atom = NonZeroAtom::read_from(&mut &[0, 0, 0x01, 0x2c][..]).unwrap();
+		assert_eq!(atom.get(), Atom::new(300));
+		assert_roundtrip!(atom);
+
+		let error = NonZeroAtom::read_from(&mut &[0; 4][..]).unwrap_err();
+		assert_eq!(error.to_string(), "unexpected zero atom");
+	}
+
+	#[test]
+	fn test_zero_atom_round_trip() {
+		assert_roundtrip!(Atom::new(0));
+	}
+
+	#[test]
+	fn test_option_atom_none() {
+		let bytes = write(&<Option<Atom>>::None);
+
+		assert_eq!(bytes, [0, 0, 0, 0]);
+	}
+}
@@ -4,6 +4,7 @@
 
 #![allow(missing_docs)]
 
+use crate::Window;
 use bitflags::bitflags;
 use xrbk_macro::{ConstantX11Size, Readable, Writable, X11Size};
 
@@ -161,56 +162,56 @@ bitflags! {
 	/// - `COLORMAP_CHANGE`
 	/// - `OWNER_GRAB_BUTTON`
 	#[derive(Default, X11Size, Readable, ConstantX11Size, Writable)]
-	pub struct CursorEventMask: u32 {
+	pub struct CursorEventMask: u16 {
 		// removes KEY_PRESS and KEY_RELEASE
 		/// Mouse button press events.
-		const BUTTON_PRESS = 0x0000_0004;
+		const BUTTON_PRESS = 0x0004;
 		/// Mouse button release events.
-		const BUTTON_RELEASE = 0x0000_0008;
+		const BUTTON_RELEASE = 0x0008;
 
 		/// Cursor events generated when the cursor enters a window.
 		///
 		/// `ENTER_WINDOW` events are generated not only when the cursor moves
 		/// to enter another window, but when the window under the cursor's
 		/// current position changes.
-		const ENTER_WINDOW = 0x0000_0010;
+		const ENTER_WINDOW = 0x0010;
 		/// Cursor events generated when the cursor leaves a window.
 		///
 		/// `LEAVE_WINDOW` events are generated not only when the cursor moves
 		/// away from a window, but when the window under the cursor's current
 		/// position moves or changes to a different window.
-		const LEAVE_WINDOW = 0x0000_0020;
+		const LEAVE_WINDOW = 0x0020;
 
 		/// Cursor motion events generated when the cursor's position changes.
-		const ANY_MOTION = 0x0000_0040;
-		const MOTION_HINT = 0x0000_0080;
+		const ANY_MOTION = 0x0040;
+		const MOTION_HINT = 0x0080;
 		/// Cursor 'drag' events when the primary mouse button is held.
 		///
 		/// The primary mouse button is usually the one on the left, but many
 		/// tools offer options to switch the positions of the primary and
 		/// secondary mouse buttons.
-		const BUTTON_1_MOTION = 0x0000_0100;
+		const BUTTON_1_MOTION = 0x0100;
 		/// Cursor 'drag' events when the middle mouse button is held.
-		const BUTTON_2_MOTION = 0x0000_0200;
+		const BUTTON_2_MOTION = 0x0200;
 		/// Cursor 'drag' events when the secondary mouse button is held.
 		///
 		/// The secondary mouse button is usually the one on the right, but many
 		/// tools offer options to switch the positions of the primary and
 		/// secondary mouse buttons.
-		const BUTTON_3_MOTION = 0x0000_0400;
+		const BUTTON_3_MOTION = 0x0400;
 		/// Cursor 'drag' events when 'mouse button 4' is held.
-		const BUTTON_4_MOTION = 0x0000_0800;
+		const BUTTON_4_MOTION = 0x0800;
 		/// Cursor 'drag' events when 'mouse button 5' is held.
-		const BUTTON_5_MOTION = 0x0000_1000;
+		const BUTTON_5_MOTION = 0x1000;
 		/// Cursor 'drag' events when any mouse button is held.
-		const ANY_BUTTON_MOTION = 0x0000_2000;
+		const ANY_BUTTON_MOTION = 0x2000;
 
 		/// Events generated after every [`EnterWindow`] and [`Focus`] event
 		/// reporting the currently held keys.
 		///
 		/// [`EnterWindow`]: crate::x11::event::EnterWindow
 		/// [`Focus`]: crate::x11::event::Focus
-		const KEY_STATE = 0x0000_4000;
+		const KEY_STATE = 0x4000;
 
 		// removes other events irrelevant to the cursor and buttons
 	}
@@ -237,21 +238,21 @@ bitflags! {
 	/// - `COLORMAP_CHANGE`
 	/// - `OWNER_GRAB_BUTTON`
 	#[derive(Default, X11Size, Readable, ConstantX11Size, Writable)]
-	pub struct DeviceEventMask: u32 {
+	pub struct DeviceEventMask: u16 {
 		/// Key press events.
-		const KEY_PRESS = 0x0000_0001;
+		const KEY_PRESS = 0x0001;
 		/// Key release events.
-		const KEY_RELEASE = 0x0000_0002;
+		const KEY_RELEASE = 0x0002;
 
 		/// Mouse button press events.
-		const BUTTON_PRESS = 0x0000_0004;
+		const BUTTON_PRESS = 0x0004;
 		/// Mouse button release events.
-		const BUTTON_RELEASE = 0x0000_0008;
+		const BUTTON_RELEASE = 0x0008;
 
 		// removes ENTER_WINDOW and LEAVE_WINDOW
 
 		/// Cursor motion events generated when the cursor's position changes.
-		const ANY_MOTION = 0x0000_0040;
+		const ANY_MOTION = 0x0040;
 
 		// removes MOTION_HINT
 
@@ -260,21 +261,21 @@ bitflags! {
 		/// The primary mouse button is usually the one on the left, but many
 		/// tools offer options to switch the positions of the primary and
 		/// secondary mouse buttons.
-		const BUTTON_1_MOTION = 0x0000_0100;
+		const BUTTON_1_MOTION = 0x0100;
 		/// Cursor 'drag' events when the middle mouse button is held.
-		const BUTTON_2_MOTION = 0x0000_0200;
+		const BUTTON_2_MOTION = 0x0200;
 		/// Cursor 'drag' events when the secondary mouse button is held.
 		///
 		/// The secondary mouse button is usually the one on the right, but many
 		/// tools offer options to switch the positions of the primary and
 		/// secondary mouse buttons.
-		const BUTTON_3_MOTION = 0x0000_0400;
+		const BUTTON_3_MOTION = 0x0400;
 		/// Cursor 'drag' events when 'mouse button 4' is held.
-		const BUTTON_4_MOTION = 0x0000_0800;
+		const BUTTON_4_MOTION = 0x0800;
 		/// Cursor 'drag' events when 'mouse button 5' is held.
-		const BUTTON_5_MOTION = 0x0000_1000;
+		const BUTTON_5_MOTION = 0x1000;
 		/// Cursor 'drag' events when any mouse button is held.
-		const ANY_BUTTON_MOTION = 0x0000_2000;
+		const ANY_BUTTON_MOTION = 0x2000;
 
 		// removes all other events from this point on
 	}
@@ -375,7 +376,7 @@ bitflags! {
 		const CONTROL = 0x0004;
 
 		/// Whether 'modifier key 1' is held.
-		const MOD_1 = 0x0009;
+		const MOD_1 = 0x0008;
 		/// Whether 'modifier key 2' is held.
 		const MOD_2 = 0x0010;
 		/// Whether 'modifier key 3' is held.
@@ -394,3 +395,170 @@ bitflags! {
 		const ANY_MODIFIER = 0x8000;
 	}
 }
+
+impl AnyModifierKeyMask {
+	/// Returns an `AnyModifierKeyMask` matching any combination of modifiers.
+	///
+	/// This is not the same as [`ModifierKeyMask::all()`], which only matches
+	/// the specific combination of every modifier key being held.
+	#[must_use]
+	pub const fn any() -> Self {
+		Self::ANY_MODIFIER
+	}
+
+	/// Returns whether this mask matches any combination of modifiers.
+	#[must_use]
+	pub const fn is_any(&self) -> bool {
+		self.contains(Self::ANY_MODIFIER)
+	}
+}
+
+impl From<ModifierKeyMask> for AnyModifierKeyMask {
+	fn from(mask: ModifierKeyMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
+	}
+}
+
+impl From<ModifierMask> for AnyModifierKeyMask {
+	/// Converts the modifier keys of the given [`ModifierMask`]; its mouse
+	/// button masks are discarded.
+	fn from(mask: ModifierMask) -> Self {
+		Self::from_bits_truncate(mask.bits())
+	}
+}
+
+/// Finds the [window] to which a device [event] is reported after propagation.
+///
+/// `masks` lists the [window] hierarchy, starting at the source [window] of
+/// the [event] and ending at the root [window], paired with the
+/// [`EventMask`] that clients have selected on each [window]. `dont_propagate`
+/// gives each [window]'s [`do_not_propagate_mask`]; [windows] without an entry
+/// are treated as having an empty [`do_not_propagate_mask`].
+///
+/// The search begins at the source [window]. The first [window] on which
+/// some client has selected `event` is returned. If a [window] on which no
+/// client has selected `event` has `event` in its [`do_not_propagate_mask`],
+/// propagation stops and [`None`] is returned. [`None`] is also returned if
+/// no [window] in the hierarchy has `event` selected.
+///
+/// [window]: Window
+/// [windows]: Window
+/// [event]: crate::message::Event
+/// [`do_not_propagate_mask`]: crate::set::Attributes::do_not_propagate_mask
+#[must_use]
+pub fn effective_event_target(
+	masks: &[(Window, EventMask)], dont_propagate: &[(Window, DeviceEventMask)], event: EventMask,
+) -> Option<Window> {
+	for (window, mask) in masks {
+		if mask.intersects(event) {
+			return Some(*window);
+		}
+
+		let blocked = dont_propagate
+			.iter()
+			.filter(|(blocking_window, _)| blocking_window == window)
+			.any(|(_, dont_propagate)| {
+				EventMask::from_bits_truncate(u32::from(dont_propagate.bits())).intersects(event)
+			});
+
+		if blocked {
+			return None;
+		}
+	}
+
+	None
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_any_modifier_key_mask_any() {
+		let any = AnyModifierKeyMask::any();
+
+		assert_eq!(any.bits(), 0x8000);
+		assert!(any.is_any());
+		assert_ne!(any, AnyModifierKeyMask::all());
+
+		assert!(!AnyModifierKeyMask::from(ModifierKeyMask::all()).is_any());
+	}
+
+	#[test]
+	fn test_any_modifier_key_mask_from_modifier_mask() {
+		let mask = ModifierMask::SHIFT | ModifierMask::MOD_1 | ModifierMask::BUTTON_1;
+
+		assert_eq!(
+			AnyModifierKeyMask::from(mask),
+			AnyModifierKeyMask::SHIFT | AnyModifierKeyMask::MOD_1,
+		);
+		assert_eq!(AnyModifierKeyMask::MOD_1.bits(), ModifierMask::MOD_1.bits());
+	}
+
+	fn window(id: u32) -> Window {
+		Window::new(id)
+	}
+
+	#[test]
+	fn test_effective_event_target_source() {
+		let masks = [
+			(window(1), EventMask::KEY_PRESS),
+			(window(2), EventMask::KEY_PRESS),
+		];
+
+		assert_eq!(
+			effective_event_target(&masks, &[], EventMask::KEY_PRESS),
+			Some(window(1)),
+		);
+	}
+
+	#[test]
+	fn test_effective_event_target_propagates() {
+		let masks = [
+			(window(1), EventMask::EXPOSURE),
+			(window(2), EventMask::empty()),
+			(window(3), EventMask::BUTTON_PRESS),
+		];
+		let dont_propagate = [(window(2), DeviceEventMask::KEY_PRESS)];
+
+		assert_eq!(
+			effective_event_target(&masks, &dont_propagate, EventMask::BUTTON_PRESS),
+			Some(window(3)),
+		);
+		assert_eq!(
+			effective_event_target(&masks, &dont_propagate, EventMask::BUTTON_RELEASE),
+			None,
+		);
+	}
+
+	#[test]
+	fn test_effective_event_target_blocked() {
+		let masks = [
+			(window(1), EventMask::empty()),
+			(window(2), EventMask::empty()),
+			(window(3), EventMask::BUTTON_PRESS),
+		];
+		let dont_propagate = [(window(2), DeviceEventMask::BUTTON_PRESS)];
+
+		assert_eq!(
+			effective_event_target(&masks, &dont_propagate, EventMask::BUTTON_PRESS),
+			None,
+		);
+	}
+
+	#[test]
+	fn test_effective_event_target_selected_despite_blocking() {
+		// A window's own selection takes precedence over its
+		// `do_not_propagate_mask`, which only affects its ancestors.
+		let masks = [
+			(window(1), EventMask::empty()),
+			(window(2), EventMask::KEY_PRESS),
+		];
+		let dont_propagate = [(window(2), DeviceEventMask::KEY_PRESS)];
+
+		assert_eq!(
+			effective_event_target(&masks, &dont_propagate, EventMask::KEY_PRESS),
+			Some(window(2)),
+		);
+	}
+}
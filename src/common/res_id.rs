@@ -3,6 +3,18 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use derive_more::{From, Into};
+use std::num::NonZeroU32;
+use xrbk::{
+	Buf,
+	BufMut,
+	ConstantX11Size,
+	ReadError,
+	ReadResult,
+	Readable,
+	Writable,
+	WriteResult,
+	X11Size,
+};
 use xrbk_macro::{new, unwrap, ConstantX11Size, Readable, Wrap, Writable, X11Size};
 
 /// A resource ID referring to either a [`Window`] or a [`Pixmap`].
@@ -93,6 +105,80 @@ impl From<Drawable> for Window {
 	}
 }
 
+impl Window {
+	/// Creates a [`NonZeroWindow`] with the given `id`.
+	///
+	/// Returns [`None`] if `id` is zero, as zero is used to represent the
+	/// absence of a [window].
+	///
+	/// [window]: Window
+	#[must_use]
+	pub const fn new_nonzero(id: u32) -> Option<NonZeroWindow> {
+		match NonZeroU32::new(id) {
+			Some(id) => Some(NonZeroWindow(id)),
+			None => None,
+		}
+	}
+}
+
+/// A [`Window`] resource ID which is known not to be zero.
+///
+/// A resource ID of zero represents the absence of a resource - `None`. A
+/// `NonZeroWindow` can therefore be used where a [window] must be present: a
+/// resource ID of zero is rejected when it is read, rather than being
+/// mistaken for a real [window].
+///
+/// [window]: Window
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NonZeroWindow(NonZeroU32);
+
+impl NonZeroWindow {
+	/// Returns this `NonZeroWindow` as a [`Window`].
+	#[must_use]
+	pub const fn get(self) -> Window {
+		Window(self.0.get())
+	}
+}
+
+impl From<NonZeroWindow> for Window {
+	fn from(window: NonZeroWindow) -> Self {
+		window.get()
+	}
+}
+
+impl TryFrom<Window> for NonZeroWindow {
+	type Error = Window;
+
+	fn try_from(window: Window) -> Result<Self, Self::Error> {
+		Window::new_nonzero(window.0).ok_or(window)
+	}
+}
+
+impl ConstantX11Size for NonZeroWindow {
+	const X11_SIZE: usize = 4;
+}
+
+impl X11Size for NonZeroWindow {
+	fn x11_size(&self) -> usize {
+		Self::X11_SIZE
+	}
+}
+
+impl Readable for NonZeroWindow {
+	fn read_from(buf: &mut impl Buf) -> ReadResult<Self> {
+		Window::new_nonzero(buf.get_u32())
+			.ok_or_else(|| ReadError::Other(Box::new("unexpected None resource")))
+	}
+}
+
+impl Writable for NonZeroWindow {
+	fn write_to(&self, buf: &mut impl BufMut) -> WriteResult {
+		buf.put_u32(self.0.get());
+
+		Ok(())
+	}
+}
+
 /// A resource ID referring to a particular pixmap resource.
 ///
 /// This is a resource ID, which means it cannot collide with the ID of any
@@ -310,3 +396,73 @@ impl From<Fontable> for GraphicsContext {
 	Wrap,
 )]
 pub struct Colormap(u32);
+
+#[cfg(test)]
+mod test {
+	use xrbk::test_support::write;
+
+	use super::*;
+
+	#[test]
+	fn test_new_nonzero() {
+		assert_eq!(Window::new_nonzero(0), None);
+		assert_eq!(
+			Window::new_nonzero(0x0040_0001).map(NonZeroWindow::get),
+			Some(Window::new(0x0040_0001)),
+		);
+	}
+
+	#[test]
+	fn test_read_nonzero_window() {
+		let window = NonZeroWindow::read_from(&mut &[0x00, 0x40, 0x00, 0x01][..]).unwrap();
+		assert_eq!(window.get(), Window::new(0x0040_0001));
+
+		let bytes = write(&window);
+		assert_eq!(bytes, [0x00, 0x40, 0x00, 0x01]);
+	}
+
+	#[test]
+	fn test_read_zero_into_nonzero_window() {
+		let error = NonZeroWindow::read_from(&mut &[0; 4][..]).unwrap_err();
+
+		assert!(matches!(error, ReadError::Other(_)));
+		assert_eq!(error.to_string(), "unexpected None resource");
+	}
+
+	#[test]
+	fn test_option_pixmap_round_trip() {
+		for (pixmap, bytes) in [
+			(None, [0; 4]),
+			(Some(Pixmap::new(0x0040_0002)), [0x00, 0x40, 0x00, 0x02]),
+		] {
+			let written = write(&pixmap);
+
+			assert_eq!(pixmap.x11_size(), 4);
+			assert_eq!(written, bytes);
+			assert_eq!(
+				<Option<Pixmap>>::read_from(&mut &bytes[..]).unwrap(),
+				pixmap
+			);
+		}
+	}
+
+	#[test]
+	fn test_option_cursor_appearance_round_trip() {
+		for (cursor_appearance, bytes) in [
+			(None, [0; 4]),
+			(
+				Some(CursorAppearance::new(0x0040_0003)),
+				[0x00, 0x40, 0x00, 0x03],
+			),
+		] {
+			let written = write(&cursor_appearance);
+
+			assert_eq!(cursor_appearance.x11_size(), 4);
+			assert_eq!(written, bytes);
+			assert_eq!(
+				<Option<CursorAppearance>>::read_from(&mut &bytes[..]).unwrap(),
+				cursor_appearance,
+			);
+		}
+	}
+}
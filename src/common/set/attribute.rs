@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::__bool;
+use super::{__bool, __u16};
 use crate::{
 	visual::ColorId,
 	BitGravity,
@@ -162,6 +162,13 @@ impl Attributes {
 	}
 }
 
+impl Default for Attributes {
+	/// Returns an empty `Attributes` set, in which nothing is configured.
+	fn default() -> Self {
+		Self::builder().build()
+	}
+}
+
 /// A builder used to construct a new [`Attributes` set].
 ///
 /// All attributes start as [`None`], and can be configured with methods on this
@@ -170,7 +177,7 @@ impl Attributes {
 ///
 /// [`build()`]: AttributesBuilder::build
 /// [`Attributes` set]: Attributes
-#[derive(Clone, Default, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct AttributesBuilder {
 	x11_size: usize,
 
@@ -200,6 +207,13 @@ pub struct AttributesBuilder {
 	cursor_appearance: Option<CursorAppearanceAttribute>,
 }
 
+impl Default for AttributesBuilder {
+	/// Equivalent to [`new()`](Self::new).
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl AttributesBuilder {
 	/// Creates a new `AttributesBuilder`.
 	///
@@ -1032,11 +1046,13 @@ impl Readable for Attributes {
 			&mut x11_size,
 			mask.contains(AttributesMask::EVENT_MASK),
 		)?;
+		// `DeviceEventMask` is two bytes, but it is written as four here.
 		let do_not_propagate_mask = super::read_set_value(
 			buf,
 			&mut x11_size,
 			mask.contains(AttributesMask::DO_NOT_PROPAGATE_MASK),
-		)?;
+		)?
+		.map(|__u16(mask)| DeviceEventMask::from_bits_truncate(mask));
 
 		let colormap =
 			super::read_set_value(buf, &mut x11_size, mask.contains(AttributesMask::COLORMAP))?;
@@ -1123,7 +1139,7 @@ impl Writable for Attributes {
 			event_mask.write_to(buf)?;
 		}
 		if let Some(do_not_propagate_mask) = &self.do_not_propagate_mask {
-			do_not_propagate_mask.write_to(buf)?;
+			__u16(do_not_propagate_mask.bits()).write_to(buf)?;
 		}
 
 		if let Some(colormap) = &self.colormap {
@@ -1161,16 +1177,16 @@ impl Readable for __BitGravity {
 	fn read_from(buf: &mut impl Buf) -> ReadResult<Self> {
 		Ok(Self(match buf.get_u32() {
 			discrim if discrim == 0 => BitGravity::Forget,
-			discrim if discrim == 1 => BitGravity::Static,
-			discrim if discrim == 2 => BitGravity::NorthWest,
-			discrim if discrim == 3 => BitGravity::North,
-			discrim if discrim == 4 => BitGravity::NorthEast,
-			discrim if discrim == 5 => BitGravity::West,
-			discrim if discrim == 6 => BitGravity::Center,
-			discrim if discrim == 7 => BitGravity::East,
-			discrim if discrim == 8 => BitGravity::SouthWest,
-			discrim if discrim == 9 => BitGravity::South,
-			discrim if discrim == 10 => BitGravity::SouthEast,
+			discrim if discrim == 1 => BitGravity::NorthWest,
+			discrim if discrim == 2 => BitGravity::North,
+			discrim if discrim == 3 => BitGravity::NorthEast,
+			discrim if discrim == 4 => BitGravity::West,
+			discrim if discrim == 5 => BitGravity::Center,
+			discrim if discrim == 6 => BitGravity::East,
+			discrim if discrim == 7 => BitGravity::SouthWest,
+			discrim if discrim == 8 => BitGravity::South,
+			discrim if discrim == 9 => BitGravity::SouthEast,
+			discrim if discrim == 10 => BitGravity::Static,
 
 			other_discrim => {
 				return Err(ReadError::UnrecognizedDiscriminant(other_discrim as usize))
@@ -1185,16 +1201,16 @@ impl Writable for __BitGravity {
 
 		match bit_gravity {
 			BitGravity::Forget => buf.put_u32(0),
-			BitGravity::Static => buf.put_u32(1),
-			BitGravity::NorthWest => buf.put_u32(2),
-			BitGravity::North => buf.put_u32(3),
-			BitGravity::NorthEast => buf.put_u32(4),
-			BitGravity::West => buf.put_u32(5),
-			BitGravity::Center => buf.put_u32(6),
-			BitGravity::East => buf.put_u32(7),
-			BitGravity::SouthWest => buf.put_u32(8),
-			BitGravity::South => buf.put_u32(9),
-			BitGravity::SouthEast => buf.put_u32(10),
+			BitGravity::NorthWest => buf.put_u32(1),
+			BitGravity::North => buf.put_u32(2),
+			BitGravity::NorthEast => buf.put_u32(3),
+			BitGravity::West => buf.put_u32(4),
+			BitGravity::Center => buf.put_u32(5),
+			BitGravity::East => buf.put_u32(6),
+			BitGravity::SouthWest => buf.put_u32(7),
+			BitGravity::South => buf.put_u32(8),
+			BitGravity::SouthEast => buf.put_u32(9),
+			BitGravity::Static => buf.put_u32(10),
 		}
 
 		Ok(())
@@ -1222,16 +1238,16 @@ impl Readable for __WindowGravity {
 	fn read_from(buf: &mut impl Buf) -> ReadResult<Self> {
 		Ok(Self(match buf.get_u32() {
 			discrim if discrim == 0 => WindowGravity::Unmap,
-			discrim if discrim == 1 => WindowGravity::Static,
-			discrim if discrim == 2 => WindowGravity::NorthWest,
-			discrim if discrim == 3 => WindowGravity::North,
-			discrim if discrim == 4 => WindowGravity::NorthEast,
-			discrim if discrim == 5 => WindowGravity::West,
-			discrim if discrim == 6 => WindowGravity::Center,
-			discrim if discrim == 7 => WindowGravity::East,
-			discrim if discrim == 8 => WindowGravity::SouthWest,
-			discrim if discrim == 9 => WindowGravity::South,
-			discrim if discrim == 10 => WindowGravity::SouthEast,
+			discrim if discrim == 1 => WindowGravity::NorthWest,
+			discrim if discrim == 2 => WindowGravity::North,
+			discrim if discrim == 3 => WindowGravity::NorthEast,
+			discrim if discrim == 4 => WindowGravity::West,
+			discrim if discrim == 5 => WindowGravity::Center,
+			discrim if discrim == 6 => WindowGravity::East,
+			discrim if discrim == 7 => WindowGravity::SouthWest,
+			discrim if discrim == 8 => WindowGravity::South,
+			discrim if discrim == 9 => WindowGravity::SouthEast,
+			discrim if discrim == 10 => WindowGravity::Static,
 
 			other_discrim => {
 				return Err(ReadError::UnrecognizedDiscriminant(other_discrim as usize))
@@ -1246,16 +1262,16 @@ impl Writable for __WindowGravity {
 
 		match window_gravity {
 			WindowGravity::Unmap => buf.put_u32(0),
-			WindowGravity::Static => buf.put_u32(1),
-			WindowGravity::NorthWest => buf.put_u32(2),
-			WindowGravity::North => buf.put_u32(3),
-			WindowGravity::NorthEast => buf.put_u32(4),
-			WindowGravity::West => buf.put_u32(5),
-			WindowGravity::Center => buf.put_u32(6),
-			WindowGravity::East => buf.put_u32(7),
-			WindowGravity::SouthWest => buf.put_u32(8),
-			WindowGravity::South => buf.put_u32(9),
-			WindowGravity::SouthEast => buf.put_u32(10),
+			WindowGravity::NorthWest => buf.put_u32(1),
+			WindowGravity::North => buf.put_u32(2),
+			WindowGravity::NorthEast => buf.put_u32(3),
+			WindowGravity::West => buf.put_u32(4),
+			WindowGravity::Center => buf.put_u32(5),
+			WindowGravity::East => buf.put_u32(6),
+			WindowGravity::SouthWest => buf.put_u32(7),
+			WindowGravity::South => buf.put_u32(8),
+			WindowGravity::SouthEast => buf.put_u32(9),
+			WindowGravity::Static => buf.put_u32(10),
 		}
 
 		Ok(())
@@ -1263,3 +1279,22 @@ impl Writable for __WindowGravity {
 }
 
 // }}}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_default_attributes_are_empty() {
+		assert!(AttributesMask::default().is_empty());
+
+		assert_eq!(AttributesBuilder::default(), AttributesBuilder::new());
+
+		let attributes = Attributes::default();
+
+		assert_eq!(attributes, Attributes::builder().build());
+		assert!(attributes.background_color().is_none());
+		assert!(attributes.event_mask().is_none());
+		assert_eq!(attributes.x11_size(), AttributesMask::X11_SIZE);
+	}
+}
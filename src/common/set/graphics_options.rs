@@ -386,6 +386,13 @@ impl GraphicsOptions {
 	}
 }
 
+impl Default for GraphicsOptions {
+	/// Returns an empty `GraphicsOptions` set, in which nothing is configured.
+	fn default() -> Self {
+		Self::builder().build()
+	}
+}
+
 /// A builder used to construct a new [`GraphicsOptions` set].
 ///
 /// All graphics options start as [`None`], and be configured with the methods
@@ -394,7 +401,7 @@ impl GraphicsOptions {
 ///
 /// [`build()`]: GraphicsOptionsBuilder::build
 /// [`GraphicsOptions` set]: GraphicsOptions
-#[derive(Clone, Default, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct GraphicsOptionsBuilder {
 	x11_size: usize,
 
@@ -437,6 +444,13 @@ pub struct GraphicsOptionsBuilder {
 	arc_mode: Option<ArcMode>,
 }
 
+impl Default for GraphicsOptionsBuilder {
+	/// Equivalent to [`new()`](Self::new).
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl GraphicsOptionsBuilder {
 	/// Creates a new `GraphicsOptionsBuilder`.
 	///
@@ -1128,10 +1142,10 @@ bitflags! {
 	/// A mask of configured options for a [`GraphicsContext`].
 	///
 	/// This mask is used in the [`GraphicsOptions` set], as well as in the
-	/// [`CopyGraphicsContext` request] to specify which options are copied.
+	/// [`CopyGraphicsOptions` request] to specify which options are copied.
 	///
 	/// [`GraphicsContext`]: crate::GraphicsContext
-	/// [`CopyGraphicsContext` request]: crate::x11::request::CopyGraphicsContext
+	/// [`CopyGraphicsOptions` request]: crate::x11::request::CopyGraphicsOptions
 	/// [`GraphicsOptions` set]: GraphicsOptions
 	#[derive(Default, X11Size, Readable, ConstantX11Size, Writable)]
 	pub struct GraphicsOptionsMask: u32 {
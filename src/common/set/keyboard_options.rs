@@ -358,6 +358,13 @@ impl KeyboardOptions {
 	}
 }
 
+impl Default for KeyboardOptions {
+	/// Returns an empty `KeyboardOptions` set, in which nothing is configured.
+	fn default() -> Self {
+		Self::builder().build()
+	}
+}
+
 /// A builder used to construct a new [`KeyboardOptions` set].
 ///
 /// All configuration options start as [`None`], and can be configured with the
@@ -366,7 +373,7 @@ impl KeyboardOptions {
 ///
 /// [`build()`]: KeyboardOptionsBuilder::build
 /// [`KeyboardOptions` set]: KeyboardOptions
-#[derive(Clone, Default, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct KeyboardOptionsBuilder {
 	x11_size: usize,
 
@@ -386,6 +393,13 @@ pub struct KeyboardOptionsBuilder {
 	auto_repeat_mode: Option<ToggleOrDefault>,
 }
 
+impl Default for KeyboardOptionsBuilder {
+	/// Equivalent to [`new()`](Self::new).
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl KeyboardOptionsBuilder {
 	/// Creates a new `KeyboardOptionsBuilder`.
 	///
@@ -78,6 +78,13 @@ impl WindowConfig {
 	}
 }
 
+impl Default for WindowConfig {
+	/// Returns an empty `WindowConfig` set, in which nothing is configured.
+	fn default() -> Self {
+		Self::builder().build()
+	}
+}
+
 /// A builder used to construct a new [`WindowConfig` set].
 ///
 /// All configuration options start as [`None`], and can be configured with
@@ -86,7 +93,7 @@ impl WindowConfig {
 ///
 /// [`build()`]: WindowConfigBuilder::build
 /// [`WindowConfig` set]: WindowConfig
-#[derive(Clone, Default, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct WindowConfigBuilder {
 	x11_size: usize,
 
@@ -104,6 +111,13 @@ pub struct WindowConfigBuilder {
 	stack_mode: Option<StackMode>,
 }
 
+impl Default for WindowConfigBuilder {
+	/// Equivalent to [`new()`](Self::new).
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl WindowConfigBuilder {
 	/// Creates a new `WindowConfigBuilder`.
 	///
@@ -224,6 +224,53 @@ impl RgbColor {
 		Ok(Self(red << BYTE, green << BYTE, blue << BYTE))
 	}
 
+	/// Creates a new `RgbColor` from 8-bit color channels.
+	///
+	/// Each 8-bit channel is scaled to the full 16-bit range of the X server,
+	/// such that `0x00` becomes `0x0000` and `0xff` becomes `0xffff`.
+	///
+	/// # Examples
+	/// ```
+	/// use xrb::visual::RgbColor;
+	///
+	/// let orange = RgbColor::from_rgb8(0xff, 0x80, 0x00);
+	/// assert_eq!(orange, RgbColor(0xffff, 0x8080, 0x0000));
+	/// ```
+	#[must_use]
+	pub const fn from_rgb8(red: u8, green: u8, blue: u8) -> Self {
+		/// Multiplying an 8-bit value by this repeats it in both bytes of a
+		/// `u16`, scaling it to the 16-bit range.
+		const SCALE: u16 = 0x0101;
+
+		Self(
+			red as u16 * SCALE,
+			green as u16 * SCALE,
+			blue as u16 * SCALE,
+		)
+	}
+
+	/// Converts this `RgbColor` to 8-bit color channels.
+	///
+	/// # Lossy
+	/// This function is lossy: the least significant byte of each color
+	/// channel will be lost during conversion.
+	///
+	/// # Examples
+	/// ```
+	/// use xrb::visual::RgbColor;
+	///
+	/// let orange = RgbColor::from_rgb8(0xff, 0x80, 0x00);
+	/// assert_eq!(orange.to_rgb8(), (0xff, 0x80, 0x00));
+	/// ```
+	#[must_use]
+	pub const fn to_rgb8(&self) -> (u8, u8, u8) {
+		let Self(red, green, blue) = self;
+		let ([red, _], [green, _], [blue, _]) =
+			(red.to_be_bytes(), green.to_be_bytes(), blue.to_be_bytes());
+
+		(red, green, blue)
+	}
+
 	/// Converts an `RgbColor` to a hex color code.
 	///
 	/// # Lossy
@@ -328,6 +375,25 @@ derive_xrb! {
 	}
 }
 
+impl Format {
+	/// Returns the number of bytes in each scanline of a Z format image of the
+	/// given `width` which uses this `Format`.
+	///
+	/// Each scanline is padded to a multiple of `scanline_pad` bits. Returns
+	/// [`None`] if `scanline_pad` is zero.
+	#[must_use]
+	pub const fn zpixmap_scanline_len(&self, width: u16) -> Option<usize> {
+		let bits = width as usize * self.bits_per_pixel as usize;
+		let pad = self.scanline_pad as usize;
+
+		if pad == 0 {
+			return None;
+		}
+
+		Some(bits.div_ceil(pad) * pad / 8)
+	}
+}
+
 derive_xrb! {
 	#[derive(Clone, Eq, PartialEq, Hash, Debug, new, X11Size, Readable, Writable)]
 	pub struct Screen {
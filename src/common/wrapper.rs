@@ -9,11 +9,14 @@ use xrbk::{
 	ReadError,
 	ReadResult,
 	Readable,
+	Wrap,
 	Writable,
 	WriteResult,
 	X11Size,
 };
 
+use xrbk_macro::from_variants;
+
 use crate::{
 	atom::Atom,
 	visual::VisualId,
@@ -22,6 +25,7 @@ use crate::{
 	Keycode,
 	Pixmap,
 	Timestamp,
+	ToggleOrDefault,
 	Window,
 	WindowClass,
 };
@@ -67,7 +71,7 @@ macro_rules! impl_writable {
 } // }}}
 
 /// Values which may be copied from the 'parent'.
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
 pub enum CopyableFromParent<T> {
 	/// A value is initialized by copying the matching value of the parent.
 	///
@@ -198,7 +202,7 @@ impl_writable!(CopyableFromParent<u8>: &self, buf {
 ///
 /// [pixmaps]: Pixmap
 /// [pixmap]: Pixmap
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
 pub enum ParentRelatable<T> {
 	/// The value of the 'parent' is used, as long as the parent has the same
 	/// `depth`.
@@ -235,9 +239,10 @@ impl_writable!(ParentRelatable<Option<Pixmap>>: &self, buf {
 
 /// Either [`Any`] value or a specific value.
 ///
-/// [`Any`]: Any::Any
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-pub enum Any<T> {
+/// [`Any`]: Specificity::Any
+#[doc(alias = "Any")]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
+pub enum Specificity<T> {
 	/// Any value.
 	Any,
 
@@ -245,38 +250,43 @@ pub enum Any<T> {
 	Other(T),
 }
 
-impl_constant_x11_size!(Any<Atom> { // {{{
+impl_constant_x11_size!(Specificity<Atom> { // {{{
 	Atom::X11_SIZE
 });
 
-impl_readable!(Any<Atom>: buf {
+impl_readable!(Specificity<Atom>: buf {
 	Ok(match buf.get_u32() {
 		discrim if discrim == 0 => Self::Any,
 		val => Self::Other(Atom::new(val)),
 	})
 });
 
-impl_writable!(Any<Atom>: &self, buf {
+impl_writable!(Specificity<Atom>: &self, buf {
 	match self {
 		Self::Any => buf.put_u32(0),
-		Self::Other(atom) => atom.write_to(buf)?,
+		Self::Other(atom) => {
+			// An `Atom` of zero is written the same as `Any`.
+			debug_assert!(!atom.is_none(), "expected a nonzero atom in Specificity::Other");
+
+			atom.write_to(buf)?;
+		},
 	}
 
 	Ok(())
 });
 
-impl_constant_x11_size!(Any<Button> {
+impl_constant_x11_size!(Specificity<Button> {
 	Button::X11_SIZE
 });
 
-impl_readable!(Any<Button>: buf {
+impl_readable!(Specificity<Button>: buf {
 	Ok(match buf.get_u8() {
 		discrim if discrim == 0 => Self::Any,
 		val => Self::Other(Button::new(val)),
 	})
 });
 
-impl_writable!(Any<Button>: &self, buf {
+impl_writable!(Specificity<Button>: &self, buf {
 	match self {
 		Self::Any => buf.put_u8(0),
 		Self::Other(button) => button.write_to(buf)?,
@@ -285,18 +295,18 @@ impl_writable!(Any<Button>: &self, buf {
 	Ok(())
 });
 
-impl_constant_x11_size!(Any<Keycode> {
+impl_constant_x11_size!(Specificity<Keycode> {
 	Keycode::X11_SIZE
 });
 
-impl_readable!(Any<Keycode>: buf {
+impl_readable!(Specificity<Keycode>: buf {
 	Ok(match buf.get_u8() {
 		discrim if discrim == 0 => Self::Any,
 		val => Self::Other(Keycode::new(val)),
 	})
 });
 
-impl_writable!(Any<Keycode>: &self, buf {
+impl_writable!(Specificity<Keycode>: &self, buf {
 	match self {
 		Self::Any => buf.put_u8(0),
 		Self::Other(keycode) => keycode.write_to(buf)?,
@@ -306,7 +316,7 @@ impl_writable!(Any<Keycode>: &self, buf {
 }); // }}}
 
 /// A time which may simply fill in for the current server time.
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
 pub enum CurrentableTime {
 	/// The X server should treat this time as its current time.
 	CurrentTime,
@@ -338,7 +348,7 @@ impl_writable!(CurrentableTime: &self, buf {
 /// The `destination` of a [`SendEvent` request].
 ///
 /// [`SendEvent` request]: crate::x11::request::SendEvent
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
 pub enum DestinationWindow {
 	/// The [window] that the cursor is currently located within.
 	///
@@ -382,7 +392,7 @@ impl_writable!(DestinationWindow: &self, buf {
 /// The [window] which is focused.
 ///
 /// [window]: Window
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
 pub enum FocusWindow {
 	/// No [window] is focused.
 	///
@@ -425,6 +435,158 @@ impl_writable!(FocusWindow: &self, buf {
 	Ok(())
 }); // }}}
 
+/// The input focus, named as in the X11 protocol.
+///
+/// This is encoded the same way as [`FocusWindow`]: [`None`] is `0` and
+/// [`PointerRoot`] is `1`. It also implements [`Wrap`], so
+/// <code>[Option]<InputFocus></code> may be used as a field, with
+/// [`Option::None`] encoded as `0`.
+///
+/// [`None`]: InputFocus::None
+/// [`PointerRoot`]: InputFocus::PointerRoot
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
+pub enum InputFocus {
+	/// This specific [window].
+	///
+	/// [window]: Window
+	Window(Window),
+
+	/// The root [window] of whichever [window] the pointer is located within
+	/// is focused.
+	///
+	/// [window]: Window
+	PointerRoot,
+
+	/// No [window] is focused.
+	///
+	/// [window]: Window
+	None,
+}
+
+impl From<u32> for InputFocus {
+	fn from(value: u32) -> Self {
+		match value {
+			0 => Self::None,
+			1 => Self::PointerRoot,
+
+			val => Self::Window(Window::new(val)),
+		}
+	}
+}
+
+impl From<InputFocus> for u32 {
+	fn from(focus: InputFocus) -> Self {
+		match focus {
+			InputFocus::None => 0,
+			InputFocus::PointerRoot => 1,
+
+			InputFocus::Window(window) => window.unwrap(),
+		}
+	}
+}
+
+impl From<FocusWindow> for InputFocus {
+	fn from(focus: FocusWindow) -> Self {
+		match focus {
+			FocusWindow::None => Self::None,
+			FocusWindow::CursorRoot => Self::PointerRoot,
+			FocusWindow::Other(window) => Self::Window(window),
+		}
+	}
+}
+
+impl From<InputFocus> for FocusWindow {
+	fn from(focus: InputFocus) -> Self {
+		match focus {
+			InputFocus::None => Self::None,
+			InputFocus::PointerRoot => Self::CursorRoot,
+			InputFocus::Window(window) => Self::Other(window),
+		}
+	}
+}
+
+impl Wrap for InputFocus {
+	type Integer = u32;
+}
+
+impl_constant_x11_size!(InputFocus { // {{{
+	u32::X11_SIZE
+});
+
+impl_readable!(InputFocus: buf {
+	Ok(Self::from(buf.get_u32()))
+});
+
+impl_writable!(InputFocus: &self, buf {
+	buf.put_u32(u32::from(*self));
+
+	Ok(())
+}); // }}}
+
+/// A value which may be left as the default.
+///
+/// For example, the `prefer_blanking` option in a [`SetScreenSaver` request]
+/// is a <code>Defaultable<[bool]></code> - `Default` in that case means to
+/// restore the default for whether blanking is preferred.
+///
+/// [`SetScreenSaver` request]: crate::x11::request::SetScreenSaver
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, from_variants)]
+pub enum Defaultable<T> {
+	/// The default value is used.
+	Default,
+
+	/// This value is used.
+	Other(T),
+}
+
+impl From<ToggleOrDefault> for Defaultable<bool> {
+	fn from(toggle: ToggleOrDefault) -> Self {
+		match toggle {
+			ToggleOrDefault::Disabled => Self::Other(false),
+			ToggleOrDefault::Enabled => Self::Other(true),
+
+			ToggleOrDefault::Default => Self::Default,
+		}
+	}
+}
+
+impl From<Defaultable<bool>> for ToggleOrDefault {
+	fn from(defaultable: Defaultable<bool>) -> Self {
+		match defaultable {
+			Defaultable::Other(false) => Self::Disabled,
+			Defaultable::Other(true) => Self::Enabled,
+
+			Defaultable::Default => Self::Default,
+		}
+	}
+}
+
+impl_constant_x11_size!(Defaultable<bool> { // {{{
+	u8::X11_SIZE
+});
+
+impl_readable!(Defaultable<bool>: buf {
+	match buf.get_u8() {
+		0 => Ok(Self::Other(false)),
+		1 => Ok(Self::Other(true)),
+
+		2 => Ok(Self::Default),
+
+		other_discrim => Err(ReadError::UnrecognizedDiscriminant(usize::from(other_discrim))),
+	}
+});
+
+impl_writable!(Defaultable<bool>: &self, buf {
+	match self {
+		Self::Other(false) => buf.put_u8(0),
+		Self::Other(true) => buf.put_u8(1),
+
+		Self::Default => buf.put_u8(2),
+	}
+
+	Ok(())
+}); // }}}
+
 /// The target of a [`KillClient` request].
 ///
 /// [`KillClient` request]: crate::x11::request::KillClient
@@ -468,3 +630,96 @@ impl_writable!(KillClientTarget: &self, buf {
 
 	Ok(())
 }); // }}}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_focus_window_none() {
+		assert_roundtrip!(FocusWindow::None);
+		assert_eq!(write(&FocusWindow::None), [0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_focus_window_cursor_root() {
+		assert_roundtrip!(FocusWindow::CursorRoot);
+		assert_eq!(write(&FocusWindow::CursorRoot), [0, 0, 0, 1]);
+	}
+
+	#[test]
+	fn test_focus_window_other() {
+		let focus = FocusWindow::Other(Window::new(0x0040_0001));
+
+		assert_roundtrip!(focus);
+		assert_eq!(write(&focus), [0x00, 0x40, 0x00, 0x01]);
+	}
+
+	#[test]
+	fn test_input_focus_window() {
+		let focus = InputFocus::Window(Window::new(0x0040_0001));
+
+		assert_roundtrip!(focus);
+		assert_eq!(write(&focus), [0x00, 0x40, 0x00, 0x01]);
+	}
+
+	#[test]
+	fn test_input_focus_pointer_root() {
+		assert_roundtrip!(InputFocus::PointerRoot);
+		assert_eq!(write(&InputFocus::PointerRoot), [0, 0, 0, 1]);
+	}
+
+	#[test]
+	fn test_input_focus_none() {
+		assert_roundtrip!(InputFocus::None);
+		assert_eq!(write(&InputFocus::None), [0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_optional_input_focus() {
+		assert_roundtrip!(Some(InputFocus::PointerRoot));
+		assert_roundtrip!(None::<InputFocus>);
+		assert_eq!(write(&None::<InputFocus>), [0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_defaultable_bool() {
+		for (defaultable, byte) in [
+			(Defaultable::Other(false), 0),
+			(Defaultable::Other(true), 1),
+			(Defaultable::Default, 2),
+		] {
+			assert_roundtrip!(defaultable);
+			assert_eq!(write(&defaultable), [byte]);
+			assert_eq!(write(&ToggleOrDefault::from(defaultable)), [byte]);
+		}
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic(expected = "expected a nonzero atom in Specificity::Other")]
+	fn test_specific_zero_atom() {
+		write(&Specificity::Other(Atom::new(0)));
+	}
+
+	#[test]
+	fn test_from_variants() {
+		assert_eq!(
+			CurrentableTime::from(Timestamp::new(42)),
+			CurrentableTime::Other(Timestamp::new(42)),
+		);
+
+		let window = Window::new(0x0040_0001);
+
+		assert_eq!(FocusWindow::from(window), FocusWindow::Other(window));
+		assert_eq!(
+			DestinationWindow::from(window),
+			DestinationWindow::Other(window),
+		);
+
+		let colormap: CopyableFromParent<Colormap> = Colormap::new(0x20).into();
+		assert_eq!(colormap, CopyableFromParent::Other(Colormap::new(0x20)));
+	}
+}
@@ -4,6 +4,12 @@
 
 //! Messages to initialize a connection with an X server.
 
+mod buffer;
+mod reader;
+
+pub use buffer::RequestBuffer;
+pub use reader::{EventReader, Message};
+
 use xrbk::X11Size;
 use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
 
@@ -186,6 +192,64 @@ derive_xrb! {
 	}
 }
 
+impl ConnectionSuccess {
+	/// Returns the [`Format`] used for images of the given `depth`, if there
+	/// is one.
+	#[must_use]
+	pub fn pixmap_format(&self, depth: u8) -> Option<&Format> {
+		self.pixmap_formats
+			.iter()
+			.find(|format| format.depth == depth)
+	}
+
+	/// Returns the number of bytes in each scanline of a bitmap of the given
+	/// `width`.
+	///
+	/// This applies to bitmaps and each bit plane of XY format images. Each
+	/// scanline is padded to a multiple of `bitmap_format_scanline_padding`
+	/// bits. Returns [`None`] if `bitmap_format_scanline_padding` is zero.
+	#[must_use]
+	pub const fn bitmap_scanline_len(&self, width: u16) -> Option<usize> {
+		let pad = self.bitmap_format_scanline_padding as usize;
+
+		if pad == 0 {
+			return None;
+		}
+
+		Some((width as usize).div_ceil(pad) * pad / 8)
+	}
+}
+
+#[cfg(test)]
+impl ConnectionSuccess {
+	/// Returns a `ConnectionSuccess` for use in tests.
+	///
+	/// Bitmaps use 32-bit scanline units and padding with a little-endian bit
+	/// order, and there are [pixmap formats] for depths `1` and `24`.
+	///
+	/// [pixmap formats]: Format
+	pub(crate) fn test_setup(image_byte_order: ImageEndianness) -> Self {
+		Self {
+			protocol_major_version: 11,
+			protocol_minor_version: 0,
+			release_number: 0,
+			resource_id_base: 0,
+			resource_id_mask: 0,
+			motion_buffer_size: 0,
+			maximum_request_length: u16::MAX,
+			image_byte_order,
+			bitmap_format_bit_order: ImageEndianness::LittleEndian,
+			bitmap_format_scanline_unit: 32,
+			bitmap_format_scanline_padding: 32,
+			min_keycode: Keycode::new(8),
+			max_keycode: Keycode::new(255),
+			vendor: String8::from(vec![]),
+			pixmap_formats: vec![Format::new(1, 1, 32), Format::new(24, 32, 32)],
+			roots: vec![],
+		}
+	}
+}
+
 #[cfg(feature = "try")]
 mod r#try {
 	use super::*;
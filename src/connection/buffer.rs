@@ -0,0 +1,198 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk::WriteError;
+
+use crate::message::Request;
+
+/// A buffer of [requests] waiting to be sent to the X server.
+///
+/// X clients commonly queue several [requests] and send them together. Each
+/// [request] pushed to a `RequestBuffer` is written in full - header, body,
+/// and padding - and is assigned the next sequence number.
+///
+/// The X server numbers the [requests] it receives from each client in order,
+/// starting from 1. The sequence number assigned to each [request] is the
+/// sequence number which will be used in any [reply], [event], or [error]
+/// generated by that [request].
+///
+/// [request]: Request
+/// [requests]: Request
+/// [reply]: crate::message::Reply
+/// [event]: crate::message::Event
+/// [error]: crate::message::Error
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RequestBuffer {
+	bytes: Vec<u8>,
+	sequences: Vec<u16>,
+
+	next_sequence: u16,
+}
+
+impl Default for RequestBuffer {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl RequestBuffer {
+	/// Creates a new, empty `RequestBuffer` for a new connection.
+	///
+	/// The first [request] pushed will be assigned the sequence number 1.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub const fn new() -> Self {
+		Self::with_next_sequence(1)
+	}
+
+	/// Creates a new, empty `RequestBuffer` which assigns `next_sequence` to
+	/// the next [request] pushed.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub const fn with_next_sequence(next_sequence: u16) -> Self {
+		Self {
+			bytes: Vec::new(),
+			sequences: Vec::new(),
+
+			next_sequence,
+		}
+	}
+
+	/// Writes the given `request` to the end of the buffer.
+	///
+	/// Returns the sequence number assigned to the `request`.
+	///
+	/// # Errors
+	/// Returns a [`WriteError`] if the `request` could not be written. In
+	/// that case, the buffer is left unchanged and no sequence number is
+	/// assigned.
+	pub fn push<R: Request>(&mut self, request: &R) -> Result<u16, WriteError> {
+		let len = self.bytes.len();
+		self.bytes.reserve(request.wire_size());
+
+		if let Err(error) = request.write_to(&mut self.bytes) {
+			self.bytes.truncate(len);
+
+			return Err(error);
+		}
+
+		let sequence = self.next_sequence;
+
+		self.sequences.push(sequence);
+		self.next_sequence = sequence.wrapping_add(1);
+
+		Ok(sequence)
+	}
+
+	/// Returns the bytes of every [request] in the buffer.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.bytes
+	}
+
+	/// Returns the sequence numbers assigned to the [requests] in the buffer,
+	/// in order.
+	///
+	/// [requests]: Request
+	#[must_use]
+	pub fn sequences(&self) -> &[u16] {
+		&self.sequences
+	}
+
+	/// Returns the sequence number which will be assigned to the next
+	/// [request] pushed.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub const fn next_sequence(&self) -> u16 {
+		self.next_sequence
+	}
+
+	/// Returns the number of [requests] in the buffer.
+	///
+	/// [requests]: Request
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.sequences.len()
+	}
+
+	/// Returns whether there are no [requests] in the buffer.
+	///
+	/// [requests]: Request
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.sequences.is_empty()
+	}
+
+	/// Removes and returns the bytes of every [request] in the buffer, ready
+	/// to be sent to the X server.
+	///
+	/// Sequence numbers continue on from those already assigned.
+	///
+	/// [request]: Request
+	pub fn drain(&mut self) -> Vec<u8> {
+		self.sequences.clear();
+
+		std::mem::take(&mut self.bytes)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		x11::request::{GetFocus, MapWindow},
+		Window,
+	};
+
+	#[test]
+	fn test_push_two_requests() {
+		let mut buffer = RequestBuffer::new();
+
+		assert_eq!(buffer.push(&GetFocus).unwrap(), 1);
+		assert_eq!(
+			buffer
+				.push(&MapWindow {
+					target: Window::new(0x0040_0001),
+				})
+				.unwrap(),
+			2,
+		);
+
+		assert_eq!(buffer.len(), 2);
+		assert_eq!(buffer.sequences(), [1, 2]);
+		assert_eq!(
+			buffer.as_bytes(),
+			[
+				43, 0, 0, 1, // GetFocus
+				8, 0, 0, 2, 0x00, 0x40, 0x00, 0x01, // MapWindow
+			],
+		);
+	}
+
+	#[test]
+	fn test_drain() {
+		let mut buffer = RequestBuffer::new();
+		buffer.push(&GetFocus).unwrap();
+
+		assert_eq!(buffer.drain(), [43, 0, 0, 1]);
+		assert!(buffer.is_empty());
+		assert!(buffer.as_bytes().is_empty());
+
+		assert_eq!(buffer.push(&GetFocus).unwrap(), 2);
+		assert_eq!(buffer.sequences(), [2]);
+	}
+
+	#[test]
+	fn test_sequence_wraps() {
+		let mut buffer = RequestBuffer::with_next_sequence(u16::MAX);
+
+		assert_eq!(buffer.push(&GetFocus).unwrap(), u16::MAX);
+		assert_eq!(buffer.push(&GetFocus).unwrap(), 0);
+	}
+}
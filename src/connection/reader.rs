@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::io::{self, Read};
+
+/// A single message received from the X server.
+///
+/// Each message is left unparsed: the first byte of a message identifies
+/// whether it is an [error], a [reply], or an [event], but which particular
+/// [reply] it is depends on the [request] that generated it.
+///
+/// [error]: crate::message::Error
+/// [reply]: crate::message::Reply
+/// [event]: crate::message::Event
+/// [request]: crate::message::Request
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Message {
+	/// An [error], identified by a first byte of `0`.
+	///
+	/// [error]: crate::message::Error
+	Error([u8; 32]),
+	/// A [reply], identified by a first byte of `1`.
+	///
+	/// This contains the reply's first 32 bytes followed by any additional
+	/// data indicated by its `length`.
+	///
+	/// [reply]: crate::message::Reply
+	Reply(Vec<u8>),
+	/// An [event], identified by a first byte of `2` or greater.
+	///
+	/// [event]: crate::message::Event
+	Event([u8; 32]),
+}
+
+/// Reads [messages] sent by the X server one at a time.
+///
+/// This is the read half of a connection: every message the X server sends is
+/// at least 32 bytes long, and only [replies] may be longer.
+///
+/// [messages]: Message
+/// [replies]: crate::message::Reply
+#[derive(Debug)]
+pub struct EventReader<R: Read> {
+	reader: R,
+}
+
+impl<R: Read> EventReader<R> {
+	/// Creates a new `EventReader` which reads [messages] from `reader`.
+	///
+	/// [messages]: Message
+	pub const fn new(reader: R) -> Self {
+		Self { reader }
+	}
+
+	/// Returns a reference to the underlying reader.
+	pub const fn get_ref(&self) -> &R {
+		&self.reader
+	}
+
+	/// Consumes the `EventReader`, returning the underlying reader.
+	pub fn into_inner(self) -> R {
+		self.reader
+	}
+
+	/// Reads the next [message] from the X server.
+	///
+	/// If the [message] is a [reply] with a non-zero `length`, the additional
+	/// `length * 4` bytes are read too.
+	///
+	/// # Errors
+	/// Returns an [`io::Error`] if reading from the underlying reader fails,
+	/// including if it ends part-way through a [message]. An error of kind
+	/// [`InvalidData`] is returned if a [reply]'s `length` is too long to be
+	/// held in memory on this target.
+	///
+	/// [`InvalidData`]: io::ErrorKind::InvalidData
+	///
+	/// [message]: Message
+	/// [reply]: crate::message::Reply
+	pub fn read_message(&mut self) -> io::Result<Message> {
+		let mut frame = [0; 32];
+		self.reader.read_exact(&mut frame)?;
+
+		Ok(match frame[0] {
+			0 => Message::Error(frame),
+
+			1 => {
+				// The reply's `length` is in 4-byte units after the first 32
+				// bytes.
+				let length = u32::from_be_bytes([frame[4], frame[5], frame[6], frame[7]]);
+				let extra = usize::try_from(length)
+					.ok()
+					.and_then(|length| length.checked_mul(4))
+					.filter(|extra| extra.checked_add(32).is_some())
+					.ok_or_else(|| {
+						io::Error::new(
+							io::ErrorKind::InvalidData,
+							format!("a reply length of {length} is too long to be read"),
+						)
+					})?;
+
+				let mut reply = frame.to_vec();
+
+				// Memory is only allocated as the bytes actually arrive,
+				// rather than trusting the `length` up front.
+				let read = (&mut self.reader)
+					.take(extra as u64)
+					.read_to_end(&mut reply)?;
+
+				if read < extra {
+					return Err(io::Error::new(
+						io::ErrorKind::UnexpectedEof,
+						format!("expected {extra} more bytes of reply data, found {read}"),
+					));
+				}
+
+				Message::Reply(reply)
+			},
+
+			_ => Message::Event(frame),
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::io::Cursor;
+
+	use xrbk::test_support::write;
+
+	use super::*;
+	use crate::{
+		unit::Px,
+		x11::{event::Expose, reply::QueryWindowTree},
+		Region,
+		Window,
+	};
+
+	#[test]
+	fn test_read_mixed_messages() {
+		let expose = Expose {
+			sequence: 1,
+			window: Window::new(0x0040_0001),
+			region: Region::new(Px(0), Px(0), Px(100), Px(50)),
+			count: 0,
+		};
+		let tree = QueryWindowTree {
+			sequence: 2,
+			root: Window::new(0x0000_0100),
+			parent: None,
+			children: vec![Window::new(0x0040_0001), Window::new(0x0040_0002)],
+		};
+		// A `Window` error for sequence number 3.
+		let mut error = [0; 32];
+		error[..8].copy_from_slice(&[0, 3, 0, 3, 0x00, 0x40, 0x00, 0x03]);
+
+		let mut bytes = write(&expose);
+		bytes.extend(write(&tree));
+		bytes.extend(error);
+
+		let mut reader = EventReader::new(Cursor::new(bytes));
+
+		match reader.read_message().unwrap() {
+			Message::Event(frame) => assert_eq!(Expose::try_from(&frame).unwrap(), expose),
+			other => panic!("expected an event, found {other:?}"),
+		}
+
+		match reader.read_message().unwrap() {
+			// The header plus two children.
+			Message::Reply(reply) => assert_eq!(reply, write(&tree)),
+			other => panic!("expected a reply, found {other:?}"),
+		}
+
+		assert_eq!(reader.read_message().unwrap(), Message::Error(error));
+
+		assert_eq!(
+			reader.read_message().unwrap_err().kind(),
+			io::ErrorKind::UnexpectedEof,
+		);
+	}
+
+	#[test]
+	fn test_read_truncated_reply() {
+		// A reply claiming the maximum `length`, followed by only 4 bytes.
+		let mut bytes = vec![1, 0, 0, 1, 0xff, 0xff, 0xff, 0xff];
+		bytes.extend([0; 24 + 4]);
+
+		let mut reader = EventReader::new(Cursor::new(bytes));
+
+		assert_eq!(
+			reader.read_message().unwrap_err().kind(),
+			io::ErrorKind::UnexpectedEof,
+		);
+	}
+}
@@ -5,7 +5,7 @@
 //! Traits defining the format of messages sent via the X11 protocol.
 
 use crate::x11::error;
-use xrbk::{Readable, Writable, X11Size};
+use xrbk::{pad, Buf, ReadError, ReadResult, Readable, Writable, WriteError, X11Size};
 
 /// A message sent from an X client to the X server.
 #[doc(notable_trait)]
@@ -23,12 +23,17 @@ pub trait Request: X11Size + Writable {
 	/// [`Implementation`], or [`Length`], this associated type should be set to
 	/// [`Infallible`].
 	///
+	/// [`derive_xrb!`] sets this to the error type given after the opcode, e.g.
+	/// `ClearAreaError` in `ClearArea: Request(61, ClearAreaError)`.
+	///
 	/// [`Alloc`]: error::Alloc
 	/// [`Implementation`]: error::Implementation
 	/// [`Length`]: error::Length
 	///
 	/// [`Infallible`]: std::convert::Infallible
+	/// [`derive_xrb!`]: xrbk_macro::derive_xrb!
 	// FIXME: what if a request generates multiple errors?
+	#[doc(alias("Error"))]
 	type OtherErrors;
 
 	/// The type of [`Reply`] generated by this `Request`.
@@ -135,6 +140,99 @@ pub trait Request: X11Size + Writable {
 
 		(size / 4) as u16
 	}
+
+	/// The value of the metabyte position: the second byte of this
+	/// `Request`'s header.
+	///
+	/// What the metabyte contains depends on the `Request`: it may be a flag,
+	/// a mode, a small count, or nothing at all. `Request`s which do not use
+	/// the metabyte position return `0`.
+	///
+	/// [`derive_xrb!`] generates this method from the `#[metabyte]` element,
+	/// if there is one, and the generated [`Writable`] implementation writes
+	/// this value in the metabyte position.
+	///
+	/// # Errors
+	/// Returns a [`WriteError`] if the value in the metabyte position could not
+	/// be written, such as if it failed to convert to its integer
+	/// representation.
+	///
+	/// [`derive_xrb!`]: xrbk_macro::derive_xrb!
+	fn metabyte(&self) -> Result<u8, WriteError> {
+		Ok(0)
+	}
+
+	/// The number of bytes this `Request` occupies when written, including
+	/// the header and any padding.
+	///
+	/// This is the [`X11Size`] of the `Request` rounded up to a multiple of 4
+	/// bytes, and can be used to reserve the exact capacity needed to write
+	/// this `Request` before writing it.
+	#[must_use]
+	fn wire_size(&self) -> usize {
+		self.x11_size() + pad(self)
+	}
+
+	/// Writes this `Request`, allowing its 4-byte header and its body to be
+	/// accessed separately.
+	///
+	/// The header contains the major opcode, the metabyte position, and the
+	/// `length()`. The body contains everything after the header, including
+	/// any padding.
+	///
+	/// The `Request` is written into a single buffer, so the header and body
+	/// are borrowed from it rather than copied. This is useful for vectored
+	/// writes (see [`SerializedRequest::io_slices`]).
+	///
+	/// # Errors
+	/// Returns a [`WriteError`] if this `Request` could not be written.
+	fn to_header_and_body(&self) -> Result<SerializedRequest, WriteError> {
+		let mut bytes = Vec::with_capacity(self.wire_size());
+		self.write_to(&mut bytes)?;
+
+		Ok(SerializedRequest { bytes })
+	}
+}
+
+/// A written [`Request`], split into its 4-byte header and its body.
+///
+/// This is returned by [`Request::to_header_and_body`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializedRequest {
+	bytes: Vec<u8>,
+}
+
+impl SerializedRequest {
+	/// The 4-byte header of the [`Request`].
+	#[must_use]
+	pub fn header(&self) -> &[u8] {
+		&self.bytes[..4]
+	}
+
+	/// The body of the [`Request`]: everything after its header.
+	#[must_use]
+	pub fn body(&self) -> &[u8] {
+		&self.bytes[4..]
+	}
+
+	/// The header and body as [`IoSlice`]s, for use with
+	/// [`Write::write_vectored`].
+	///
+	/// [`IoSlice`]: std::io::IoSlice
+	/// [`Write::write_vectored`]: std::io::Write::write_vectored
+	#[must_use]
+	pub fn io_slices(&self) -> [std::io::IoSlice<'_>; 2] {
+		[
+			std::io::IoSlice::new(self.header()),
+			std::io::IoSlice::new(self.body()),
+		]
+	}
+
+	/// Returns the whole written [`Request`], header included.
+	#[must_use]
+	pub fn into_bytes(self) -> Vec<u8> {
+		self.bytes
+	}
 }
 
 /// The result of sending a [request].
@@ -221,6 +319,15 @@ pub trait Reply: X11Size + Readable {
 	/// [request]: Request
 	type Request: Request<Reply = Self>;
 
+	/// The [major opcode] of the [request] that generates this `Reply`.
+	///
+	/// This can be used to check that a `Reply` was decoded for the expected
+	/// [request].
+	///
+	/// [major opcode]: Request::MAJOR_OPCODE
+	/// [request]: Reply::Request
+	const FOR_MAJOR_OPCODE: u8 = <Self::Request as Request>::MAJOR_OPCODE;
+
 	/// The size of this `Reply` in 4-byte units minus 8.
 	///
 	/// ***Implementors: please see the [implementation notes section][impl] at
@@ -317,6 +424,38 @@ pub trait Reply: X11Size + Readable {
 	///
 	/// [request]: Request
 	fn sequence(&self) -> u16;
+
+	/// Reads this `Reply` from a message, including its first byte.
+	///
+	/// The first byte of a message received from the X server identifies what
+	/// type of message it is: `0` for an [error], `1` for a `Reply`, and `2`
+	/// or greater for an [event]. If the X server sends an [error] in place of
+	/// an expected `Reply`, this returns an error rather than misparsing the
+	/// [error] as this `Reply`.
+	///
+	/// # Errors
+	/// Returns [`ReadError::UnexpectedReplyType`] if the first byte is not
+	/// `1`, or any [`ReadError`] generated when reading the rest of the
+	/// `Reply`.
+	///
+	/// [error]: Error
+	/// [event]: Event
+	fn read_reply(buf: &mut impl Buf) -> ReadResult<Self>
+	where
+		Self: Sized,
+	{
+		if !buf.has_remaining() {
+			return Err(ReadError::UnexpectedEnd {
+				expected: 1,
+				remaining: 0,
+			});
+		}
+
+		match buf.get_u8() {
+			1 => Self::read_from(buf),
+			found => Err(ReadError::UnexpectedReplyType { found }),
+		}
+	}
 }
 
 /// A message sent from the X server to an X client.
@@ -335,6 +474,21 @@ pub trait Event: X11Size + Readable + Writable {
 	/// events.
 	const CODE: u8;
 
+	/// Returns whether the given event code identifies this `Event`.
+	///
+	/// The most significant bit of an event code is set if the `Event` was
+	/// sent with a [`SendEvent` request]; it is ignored here, so that `code`
+	/// may be taken directly from the first byte of an `Event`.
+	///
+	/// [`SendEvent` request]: crate::x11::request::SendEvent
+	#[must_use]
+	fn matches_code(code: u8) -> bool
+	where
+		Self: Sized,
+	{
+		code & 0x7f == Self::CODE
+	}
+
 	/// The sequence number associated with the last [request] received that
 	/// was related to this `Event`.
 	///
@@ -370,3 +524,237 @@ pub trait Error: X11Size + Readable {
 	/// [major opcode]: Request::MAJOR_OPCODE
 	fn major_opcode(&self) -> u8;
 }
+
+#[cfg(test)]
+mod test {
+	extern crate self as xrb;
+
+	use xrbk::{
+		assert_roundtrip,
+		pad,
+		test_support::write,
+		ReadError,
+		Readable,
+		Writable,
+		WriteError,
+		X11Size,
+	};
+	use xrbk_macro::derive_xrb;
+
+	use super::{Reply, Request};
+	use crate::{
+		set::Attributes,
+		unit::Px,
+		x11::{
+			reply::GetFocus,
+			request::{ChangeWindowAttributes, ClearArea, RevertFocus},
+		},
+		FocusWindow,
+		Rectangle,
+		Window,
+	};
+
+	derive_xrb! {
+		#[derive(Debug, PartialEq, Eq, X11Size, Readable, Writable)]
+		struct Offset {
+			first: u16,
+
+			#[offset(8)]
+			second: u32,
+		}
+
+		#[derive(Debug, PartialEq, Eq, X11Size, Readable, Writable)]
+		struct OffsetRequest: Request(200) {
+			first: u16,
+
+			#[offset(8)]
+			second: u32,
+		}
+
+		#[derive(Debug, PartialEq, Eq, X11Size, Readable, Writable)]
+		struct Misplaced {
+			first: u64,
+
+			#[offset(4)]
+			second: u32,
+		}
+
+		#[derive(Debug, PartialEq, Eq, X11Size, Readable, Writable)]
+		struct Labelled: Request(201) {
+			#[hide(Writable)]
+			label: u32,
+
+			#[context(self::remaining => remaining)]
+			data: Vec<u8>,
+			[_; data => pad(data)],
+		}
+	}
+
+	#[test]
+	fn test_read_reply() {
+		let reply = GetFocus {
+			sequence: 9,
+			revert_to: RevertFocus::None,
+			focus: FocusWindow::Other(Window::new(0x0040_0001)),
+		};
+
+		let bytes = write(&reply);
+
+		assert_eq!(GetFocus::read_reply(&mut &bytes[..]).unwrap(), reply);
+	}
+
+	#[test]
+	fn test_read_reply_from_error() {
+		// A `Window` error for sequence number 9.
+		let mut bytes = [0; 32];
+		bytes[..8].copy_from_slice(&[0, 3, 0, 9, 0x00, 0x40, 0x00, 0x01]);
+
+		assert!(matches!(
+			GetFocus::read_reply(&mut &bytes[..]),
+			Err(ReadError::UnexpectedReplyType { found: 0 }),
+		));
+		assert!(matches!(
+			GetFocus::read_reply(&mut &[][..]),
+			Err(ReadError::UnexpectedEnd { .. }),
+		));
+	}
+
+	#[test]
+	fn test_request_to_header_and_body() {
+		let mut attributes = Attributes::builder();
+		attributes.override_redirect(true);
+
+		let request = ChangeWindowAttributes {
+			target: Window::new(0x0040_0001),
+			attributes: attributes.build(),
+		};
+
+		let request_bytes = request.to_header_and_body().unwrap();
+		let (header, body) = (request_bytes.header(), request_bytes.body());
+
+		let bytes = write(&request);
+
+		assert_eq!(header, [2, 0, 0, 4]);
+		assert_eq!(body, &bytes[4..]);
+		assert_eq!(request_bytes.io_slices().map(|slice| slice.len()), [4, 12]);
+		assert_eq!(
+			body,
+			[
+				0x00, 0x40, 0x00, 0x01, // target
+				0x00, 0x00, 0x02, 0x00, // attribute mask: override_redirect
+				0x00, 0x00, 0x00, 0x01, // override_redirect
+			],
+		);
+		assert_eq!(request_bytes.into_bytes(), bytes);
+	}
+
+	#[test]
+	fn test_request_metabyte() {
+		let clear = |graphics_exposure| ClearArea {
+			graphics_exposure,
+			target: Window::new(1),
+			area: Rectangle::new(Px(0), Px(0), Px(0), Px(0)),
+		};
+
+		for (request, metabyte) in [(clear(true), 1), (clear(false), 0)] {
+			let bytes = request.to_header_and_body().unwrap();
+
+			assert_eq!(request.metabyte().unwrap(), metabyte);
+			assert_eq!(bytes.header()[1], metabyte);
+		}
+
+		// Requests without a metabyte element use the default of `0`.
+		let request = ChangeWindowAttributes {
+			target: Window::new(1),
+			attributes: Attributes::default(),
+		};
+		assert_eq!(request.metabyte().unwrap(), 0);
+	}
+
+	#[test]
+	fn test_offset_attribute() {
+		let offset = Offset {
+			first: 0x0102,
+			second: 0x0304_0506,
+		};
+
+		assert_roundtrip!(offset);
+		assert_eq!(offset.x11_size(), 12);
+
+		let bytes = write(&offset);
+
+		// `second` begins at offset 8, after 6 unused bytes.
+		assert_eq!(bytes, [1, 2, 0, 0, 0, 0, 0, 0, 3, 4, 5, 6]);
+	}
+
+	#[test]
+	fn test_offset_attribute_request_body() {
+		let request = OffsetRequest {
+			first: 0x0102,
+			second: 0x0304_0506,
+		};
+
+		assert_roundtrip!(request, skip = 1);
+		assert_eq!(request.x11_size(), 16);
+
+		// The offset is measured from the end of the 4-byte header.
+		assert_eq!(
+			write(&request),
+			[200, 0, 0, 4, 1, 2, 0, 0, 0, 0, 0, 0, 3, 4, 5, 6],
+		);
+	}
+
+	#[test]
+	fn test_offset_attribute_truncated() {
+		// The message ends part way through the unused bytes before `second`.
+		let bytes = [0, 0, 4, 1, 2, 0, 0];
+
+		assert!(matches!(
+			OffsetRequest::read_from(&mut &bytes[..]),
+			Err(ReadError::UnexpectedEnd {
+				expected: 6,
+				remaining: 2,
+			}),
+		));
+	}
+
+	#[test]
+	fn test_offset_attribute_misplaced() {
+		let misplaced = Misplaced {
+			first: 0,
+			second: 0,
+		};
+
+		assert!(matches!(
+			misplaced.write_to(&mut vec![]),
+			Err(WriteError::MisplacedField {
+				field: "second",
+				offset: 4,
+				size: 8,
+			}),
+		));
+		assert!(matches!(
+			Misplaced::read_from(&mut &[0; 12][..]),
+			Err(ReadError::MisplacedField {
+				field: "second",
+				offset: 4,
+				size: 8,
+			}),
+		));
+	}
+
+	#[test]
+	fn test_hidden_writable_field_is_not_read() {
+		let labelled = Labelled {
+			label: u32::default(),
+			data: vec![1, 2, 3, 4, 5, 6, 7, 8],
+		};
+
+		// The `label` takes no space, so the `data` follows the header.
+		assert_eq!(write(&labelled)[4..], [1, 2, 3, 4, 5, 6, 7, 8]);
+
+		// Neither the `label` nor the bytes it would have taken are read, so
+		// all of the remaining bytes are read as `data`.
+		assert_roundtrip!(labelled, skip = 1);
+	}
+}
@@ -507,3 +507,105 @@ impl PartialOrd<SignedPercentage> for i8 {
 }
 
 impl_xrbk_traits!(SignedPercentage(i8));
+
+/// An angle measured in 64ths of a degree.
+///
+/// X measures angles, such as those of an [`Arc`], in degrees scaled by 64.
+/// Positive angles indicate counterclockwise motion and negative angles
+/// indicate clockwise motion.
+///
+/// [`Arc`]: crate::Arc
+#[derive(
+	Debug,
+	Hash,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Add,
+	AddAssign,
+	Sub,
+	SubAssign,
+	Mul,
+	MulAssign,
+	Div,
+	DivAssign,
+	Rem,
+	RemAssign,
+	Sum,
+)]
+pub struct Angle(pub i16);
+
+impl Angle {
+	/// The number of units in one degree.
+	pub const UNITS_PER_DEGREE: i16 = 64;
+
+	/// Creates a new `Angle` from the given number of degrees.
+	///
+	/// The angle is rounded to the nearest 64th of a degree, saturating at
+	/// the bounds of an [`i16`].
+	#[must_use]
+	#[allow(
+		clippy::cast_possible_truncation,
+		reason = "float to int casts saturate at the bounds of an i16"
+	)]
+	pub fn from_degrees(degrees: f32) -> Self {
+		Self((degrees * f32::from(Self::UNITS_PER_DEGREE)).round() as i16)
+	}
+
+	/// Returns this `Angle` measured in degrees.
+	#[must_use]
+	pub fn degrees(&self) -> f32 {
+		f32::from(self.0) / f32::from(Self::UNITS_PER_DEGREE)
+	}
+
+	/// Returns the wrapped angle value, measured in 64ths of a degree.
+	#[must_use]
+	pub const fn unwrap(&self) -> i16 {
+		self.0
+	}
+}
+
+impl Display for Angle {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}°", self.degrees())
+	}
+}
+
+impl_xrbk_traits!(Angle(i16));
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_angle_from_degrees() {
+		assert_eq!(Angle::from_degrees(0.0), Angle(0));
+		assert_eq!(Angle::from_degrees(90.0), Angle(5760));
+		assert_eq!(Angle::from_degrees(-360.0), Angle(-23040));
+		assert_eq!(Angle::from_degrees(0.5), Angle(32));
+
+		assert_eq!(Angle::from_degrees(1000.0), Angle(i16::MAX));
+	}
+
+	#[test]
+	fn test_angle_degrees() {
+		assert!((Angle(5760).degrees() - 90.0).abs() < f32::EPSILON);
+		assert!((Angle(-16).degrees() + 0.25).abs() < f32::EPSILON);
+		assert!((Angle::from_degrees(45.5).degrees() - 45.5).abs() < f32::EPSILON);
+	}
+
+	#[test]
+	fn test_angle_round_trip() {
+		assert_roundtrip!(Angle(5760));
+		assert_roundtrip!(Angle(-23040));
+
+		let bytes = write(&Angle(5760));
+
+		assert_eq!(bytes, 5760_i16.to_be_bytes());
+	}
+}
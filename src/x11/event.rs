@@ -11,6 +11,7 @@
 
 extern crate self as xrb;
 
+use array_init::array_init;
 use bitflags::bitflags;
 use derivative::Derivative;
 
@@ -48,7 +49,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`KEY_PRESS`]: crate::EventMask::KEY_PRESS
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct KeyPress: Event(2) {
 		/// The [sequence number] associated with the last [request] related
@@ -129,7 +130,7 @@ derive_xrb! {
 	///
 	/// [event]: Event
 	/// [`KEY_RELEASE`]: crate::EventMask::KEY_RELEASE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct KeyRelease: Event(3) {
 		/// The [sequence number] associated with the last [request] related
@@ -213,7 +214,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [mouse button]: Button
 	/// [`BUTTON_PRESS`]: crate::EventMask::BUTTON_PRESS
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct ButtonPress: Event(4) {
 		/// The [sequence number] associated with the last [request] related
@@ -295,7 +296,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [mouse button]: Button
 	/// [`BUTTON_RELEASE`]: crate::EventMask::BUTTON_RELEASE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct ButtonRelease: Event(5) {
 		/// The [sequence number] associated with the last [request] related
@@ -440,7 +441,7 @@ derive_xrb! {
 	///
 	/// [event]: Event
 	/// [window]: Window
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Motion: Event(6) {
 		/// The [sequence number] associated with the last [request] related
@@ -645,7 +646,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`ENTER_WINDOW`]: crate::EventMask::ENTER_WINDOW
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct EnterWindow: Event(7) {
 		/// The [sequence number] associated with the last [request] related
@@ -736,7 +737,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`LEAVE_WINDOW`]: crate::EventMask::LEAVE_WINDOW
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct LeaveWindow: Event(8) {
 		/// The [sequence number] associated with the last [request] related
@@ -1025,7 +1026,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`FOCUS_CHANGE`]: crate::EventMask::FOCUS_CHANGE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Focus: Event(9) {
 		/// The [sequence number] associated with the last [request] related
@@ -1053,7 +1054,7 @@ derive_xrb! {
 		///
 		/// [`Normal`] for normal `Focus` events, [`Grab`] and [`Ungrab`] for
 		/// events generated by grabs and ungrabs, [`WhileGrabbed`] for events
-		/// generated by a [`SetInputFocus` request] while the keyboard is
+		/// generated by a [`SetFocus` request] while the keyboard is
 		/// grabbed.
 		///
 		/// [event]: Event
@@ -1063,7 +1064,7 @@ derive_xrb! {
 		/// [`Ungrab`]: FocusGrabMode::Ungrab
 		/// [`WhileGrabbed`]: FocusGrabMode::WhileGrabbed
 		///
-		/// [`SetInputFocus` request]: super::request::SetInputFocus
+		/// [`SetFocus` request]: super::request::SetFocus
 		pub grab_mode: FocusGrabMode,
 		[_; ..],
 	}
@@ -1084,7 +1085,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`FOCUS_CHANGE`]: crate::EventMask::FOCUS_CHANGE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Unfocus: Event(10) {
 		/// The [sequence number] associated with the last [request] related
@@ -1112,7 +1113,7 @@ derive_xrb! {
 		///
 		/// [`Normal`] for normal `Focus` events, [`Grab`] and [`Ungrab`] for
 		/// events generated by grabs and ungrabs, [`WhileGrabbed`] for events
-		/// generated by a [`SetInputFocus` request] while the keyboard is
+		/// generated by a [`SetFocus` request] while the keyboard is
 		/// grabbed.
 		///
 		/// [event]: Event
@@ -1122,7 +1123,7 @@ derive_xrb! {
 		/// [`Ungrab`]: FocusGrabMode::Ungrab
 		/// [`WhileGrabbed`]: FocusGrabMode::WhileGrabbed
 		///
-		/// [`SetInputFocus` request]: super::request::SetInputFocus
+		/// [`SetFocus` request]: super::request::SetFocus
 		pub grab_mode: FocusGrabMode,
 		[_; ..],
 	}
@@ -1133,10 +1134,14 @@ derive_xrb! {
 	/// This [event] is reported to clients selecting [`KEYBOARD_STATE`] on a
 	/// [window] immediately after every [`EnterWindow`] and [`Focus`] event.
 	///
+	/// Unlike every other [event], `KeyboardState` has no sequence number: its
+	/// `keys` immediately follow the event code, starting at byte 1.
+	///
 	/// [event]: Event
 	/// [window]: Window
 	/// [`KEYBOARD_STATE`]: crate::EventMask::KEYBOARD_STATE
-	#[derive(Debug, Hash, X11Size, Readable, Writable)]
+	#[doc(alias = "KeymapNotify")]
+	#[derive(Debug, Hash, PartialEq, Eq, X11Size, ConstantX11Size, Readable, Writable)]
 	pub struct KeyboardState: Event(11) {
 		/// A bit vector representing the current keyboard state.
 		///
@@ -1145,6 +1150,11 @@ derive_xrb! {
 		/// contains the bits for [keycodes] `8N` to `8N + 7`, with the least
 		/// significant bit in the byte representing key `8N`.
 		///
+		/// Since [keycodes] 0 to 7 are not present, the [keycode] `K` is found
+		/// at index `K / 8 - 1` of this array. See
+		/// [`is_pressed`](KeyboardState::is_pressed).
+		///
+		/// [keycode]: Keycode
 		/// [keycodes]: Keycode
 		pub keys: [u8; 31],
 	}
@@ -1178,7 +1188,7 @@ derive_xrb! {
 	/// [`WindowClass::InputOnly`]: crate::WindowClass::InputOnly
 	///
 	/// [`EXPOSURE`]: crate::EventMask::EXPOSURE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Expose: Event(12) {
 		/// The [sequence number] associated with the last [request] related
@@ -1217,7 +1227,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [`GraphicsContext`]: crate::GraphicsContext
 	/// [`graphics_exposure`]: crate::set::GraphicsOptions::graphics_exposure
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct GraphicsExposure: Event(13) {
 		/// The [sequence number] associated with the last [request] related
@@ -1272,7 +1282,7 @@ derive_xrb! {
 	/// [`GraphicsExposure` events]: GraphicsExposure
 	/// [`GraphicsContext`]: crate::GraphicsContext
 	/// [`graphics_exposure`]: crate::set::GraphicsOptions::graphics_exposure
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct NoExposure: Event(14) {
 		/// The [sequence number] associated with the last [request] related
@@ -1376,7 +1386,7 @@ derive_xrb! {
 	/// [`FullyObscured`]: VisibilityState::FullyObscured
 	///
 	/// [`VISIBILITY_CHANGE`]: crate::EventMask::VISIBILITY_CHANGE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Visibility: Event(15) {
 		/// The [sequence number] associated with the last [request] related
@@ -1405,7 +1415,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Create: Event(16) {
 		/// The [sequence number] associated with the last [request] related
@@ -1462,7 +1472,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Destroy: Event(17) {
 		/// The [sequence number] associated with the last [request] related
@@ -1502,7 +1512,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Unmap: Event(18) {
 		/// The [sequence number] associated with the last [request] related
@@ -1550,7 +1560,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Map: Event(19) {
 		/// The [sequence number] associated with the last [request] related
@@ -1605,7 +1615,7 @@ derive_xrb! {
 	/// [`MapWindow` request]: super::request::MapWindow
 	///
 	/// [`SUBSTRUCTURE_REDIRECT`]: crate::EventMask::SUBSTRUCTURE_REDIRECT
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct MapWindowRequest: Event(20) {
 		/// The [sequence number] associated with the last [request] related
@@ -1641,7 +1651,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Reparent: Event(21) {
 		/// The [sequence number] associated with the last [request] related
@@ -1699,7 +1709,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Configure: Event(22) {
 		/// The [sequence number] associated with the last [request] related
@@ -1781,7 +1791,7 @@ derive_xrb! {
 	/// [`ConfigureWindow` request]: super::request::ConfigureWindow
 	///
 	/// [`SUBSTRUCTURE_REDIRECT`]: crate::EventMask::SUBSTRUCTURE_REDIRECT
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct ConfigureWindowRequest: Event(23) {
 		/// The [sequence number] associated with the last [request] related
@@ -1846,7 +1856,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Gravity: Event(24) {
 		/// The [sequence number] associated with the last [request] related
@@ -1889,7 +1899,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`RESIZE_REDIRECT`]: crate::EventMask::RESIZE_REDIRECT
 	/// [`ConfigureWindow` request]: super::request::ConfigureWindow
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct ResizeRequest: Event(25) {
 		/// The [sequence number] associated with the last [request] related
@@ -1951,7 +1961,7 @@ derive_xrb! {
 	///
 	/// [`STRUCTURE_NOTIFY`]: crate::EventMask::STRUCTURE_NOTIFY
 	/// [`SUBSTRUCTURE_NOTIFY`]: crate::EventMask::SUBSTRUCTURE_NOTIFY
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Circulate: Event(26) {
 		/// The [sequence number] associated with the last [request] related
@@ -1994,7 +2004,7 @@ derive_xrb! {
 	/// [window]: Window
 	/// [`SUBSTRUCTURE_REDIRECT`]: crate::EventMask::SUBSTRUCTURE_REDIRECT
 	/// [`CirculateWindow` request]: super::request::CirculateWindow
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct CirculateWindowRequest: Event(27) {
 		/// The [sequence number] associated with the last [request] related
@@ -2054,7 +2064,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [window]: Window
 	/// [`PROPERTY_CHANGE`]: crate::EventMask::PROPERTY_CHANGE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Property: Event(28) {
 		/// The [sequence number] associated with the last [request] related
@@ -2093,7 +2103,7 @@ derive_xrb! {
 	///
 	/// [event]: Event
 	/// [`SetSelectionOwner` request]: super::request::SetSelectionOwner
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct SelectionClear: Event(29) {
 		/// The [sequence number] associated with the last [request] related
@@ -2131,7 +2141,7 @@ derive_xrb! {
 	/// [`ConvertSelection` request]: super::request::ConvertSelection
 	/// [`Selection` event]: Selection
 	/// [`SendEvent` request]: super::request::SendEvent
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct ConvertSelectionRequest: Event(30) {
 		/// The [sequence number] associated with the last [request] related
@@ -2159,8 +2169,16 @@ derive_xrb! {
 		/// The selection to be converted.
 		pub selection: Atom,
 		/// The type that the `selection` should be converted into.
+		#[doc(alias = "target")]
 		pub target_type: Atom,
-		// TODO: undocumented in core protocol, check ICCCM
+		/// The property on the `requester` which the converted `selection`
+		/// should be stored in.
+		///
+		/// [`None`] is sent by obsolete clients. In that case, the [ICCCM]
+		/// specifies that the owner should use the `target_type` as the
+		/// property.
+		///
+		/// [ICCCM]: https://x.org/releases/X11R7.7/doc/xorg-docs/icccm/icccm.html#Responsibilities_of_the_Selection_Owner
 		pub property: Option<Atom>,
 		[_; ..],
 	}
@@ -2178,7 +2196,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [`ConvertSelection` request]: super::request::ConvertSelection
 	/// [`SendEvent` request]: super::request::SendEvent
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Selection: Event(31) {
 		/// The [sequence number] associated with the last [request] related
@@ -2206,8 +2224,12 @@ derive_xrb! {
 		/// The type that the `selection` was to be converted into.
 		///
 		/// The `selection` may or may not have been converted.
+		#[doc(alias = "target")]
 		pub target_type: Atom,
-		// TODO: undocumented in core protocol, check ICCCM.
+		/// The property on the `requester` which the converted `selection` was
+		/// stored in.
+		///
+		/// This is [`None`] if the `selection` could not be converted.
 		pub property: Option<Atom>,
 		[_; ..],
 	}
@@ -2258,7 +2280,7 @@ derive_xrb! {
 	/// [`colormap` attribute]: crate::Attributes::colormap
 	///
 	/// [`COLORMAP_CHANGE`]: crate::EventMask::COLORMAP_CHANGE
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct Colormap: Event(32) {
 		/// The [sequence number] associated with the last [request] related
@@ -2321,6 +2343,89 @@ pub enum ClientMessageData {
 	I32([i32; 5]),
 }
 
+impl ClientMessageData {
+	/// Returns the [`ClientMessageFormat`] of this data.
+	#[must_use]
+	pub const fn format(&self) -> ClientMessageFormat {
+		match self {
+			Self::I8(_) => ClientMessageFormat::I8,
+			Self::I16(_) => ClientMessageFormat::I16,
+			Self::I32(_) => ClientMessageFormat::I32,
+		}
+	}
+
+	/// Returns the 20 bytes of this data as they are encoded.
+	fn to_bytes(&self) -> [u8; 20] {
+		let mut bytes = [0; 20];
+
+		match self {
+			Self::I8(values) => {
+				for (chunk, value) in bytes.chunks_exact_mut(1).zip(values) {
+					chunk.copy_from_slice(&value.to_be_bytes());
+				}
+			},
+
+			Self::I16(values) => {
+				for (chunk, value) in bytes.chunks_exact_mut(2).zip(values) {
+					chunk.copy_from_slice(&value.to_be_bytes());
+				}
+			},
+
+			Self::I32(values) => {
+				for (chunk, value) in bytes.chunks_exact_mut(4).zip(values) {
+					chunk.copy_from_slice(&value.to_be_bytes());
+				}
+			},
+		}
+
+		bytes
+	}
+
+	/// Returns this data interpreted as 20 `i8` values, regardless of its
+	/// [format].
+	///
+	/// The bytes of this data are reinterpreted as they are encoded.
+	///
+	/// [format]: ClientMessageData::format
+	#[must_use]
+	pub fn as_i8(&self) -> [i8; 20] {
+		self.to_bytes().map(|byte| i8::from_be_bytes([byte]))
+	}
+
+	/// Returns this data interpreted as 10 `i16` values, regardless of its
+	/// [format].
+	///
+	/// The bytes of this data are reinterpreted as they are encoded.
+	///
+	/// [format]: ClientMessageData::format
+	#[must_use]
+	pub fn as_i16(&self) -> [i16; 10] {
+		let bytes = self.to_bytes();
+
+		array_init(|i| i16::from_be_bytes([bytes[2 * i], bytes[2 * i + 1]]))
+	}
+
+	/// Returns this data interpreted as 5 `i32` values, regardless of its
+	/// [format].
+	///
+	/// The bytes of this data are reinterpreted as they are encoded.
+	///
+	/// [format]: ClientMessageData::format
+	#[must_use]
+	pub fn as_i32(&self) -> [i32; 5] {
+		let bytes = self.to_bytes();
+
+		array_init(|i| {
+			i32::from_be_bytes([
+				bytes[4 * i],
+				bytes[4 * i + 1],
+				bytes[4 * i + 2],
+				bytes[4 * i + 3],
+			])
+		})
+	}
+}
+
 impl ConstantX11Size for ClientMessageData {
 	const X11_SIZE: usize = 20;
 }
@@ -2356,7 +2461,7 @@ derive_xrb! {
 	/// [event]: Event
 	/// [`SendEvent` request]: super::request::SendEvent
 	/// [window]: Window
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct ClientMessage: Event(33) {
 		/// The [sequence number] associated with the last [request] related
@@ -2371,11 +2476,7 @@ derive_xrb! {
 
 		/// Whether `data` is `[i8; 20]`, `[i16; 10]`, or `[i32; 5]`.
 		#[metabyte]
-		let format: ClientMessageFormat = data => match data {
-			ClientMessageData::I8(_) => ClientMessageFormat::I8,
-			ClientMessageData::I16(_) => ClientMessageFormat::I16,
-			ClientMessageData::I32(_) => ClientMessageFormat::I32,
-		},
+		let format: ClientMessageFormat = data => data.format(),
 
 		/// The recipient of this `ClientMessage` event.
 		pub window: Window,
@@ -2390,6 +2491,103 @@ derive_xrb! {
 	}
 }
 
+impl KeyboardState {
+	/// Returns whether the key with the given [keycode] is currently pressed.
+	///
+	/// [Keycodes] 0 to 7 are not represented in the `keys`, so this always
+	/// returns `false` for them.
+	///
+	/// [keycode]: Keycode
+	/// [Keycodes]: Keycode
+	#[must_use]
+	pub const fn is_pressed(&self, keycode: Keycode) -> bool {
+		let keycode = keycode.unwrap() as usize;
+
+		if keycode < 8 {
+			return false;
+		}
+
+		// Byte `0` of `keys` holds the bits for keycodes 8 to 15.
+		self.keys[keycode / 8 - 1] & (1 << (keycode % 8)) != 0
+	}
+}
+
+impl ConvertSelectionRequest {
+	/// Returns the property which the converted `selection` should be stored
+	/// in.
+	///
+	/// As specified in the [ICCCM], this is the `target_type` if the
+	/// `property` is [`None`].
+	///
+	/// [ICCCM]: https://x.org/releases/X11R7.7/doc/xorg-docs/icccm/icccm.html#Responsibilities_of_the_Selection_Owner
+	#[must_use]
+	pub const fn property_or_target(&self) -> Atom {
+		match self.property {
+			Some(property) => property,
+			None => self.target_type,
+		}
+	}
+
+	/// Creates the [`Selection` event] which the owner should send back to the
+	/// `requester`, as defined in the [ICCCM].
+	///
+	/// `property` is the property on the `requester` which the converted
+	/// `selection` was stored in, or [`None`] if the `selection` could not be
+	/// converted. The [`Selection` event] should be sent to the `requester`
+	/// using a [`SendEvent` request] with an empty event mask.
+	///
+	/// [ICCCM]: https://x.org/releases/X11R7.7/doc/xorg-docs/icccm/icccm.html#Responsibilities_of_the_Selection_Owner
+	/// [`Selection` event]: Selection
+	/// [`SendEvent` request]: super::request::SendEvent
+	#[must_use]
+	pub const fn reply(&self, property: Option<Atom>) -> Selection {
+		Selection {
+			sequence: 0,
+
+			time: self.time,
+
+			requester: self.requester,
+			selection: self.selection,
+			target_type: self.target_type,
+			property,
+		}
+	}
+}
+
+impl ClientMessage {
+	/// Creates a new `ClientMessage` event for the given `protocol` of the
+	/// `WM_PROTOCOLS` property, as defined in the [ICCCM].
+	///
+	/// The `r#type` of the event is set to `wm_protocols`, which should be the
+	/// [atom] interned for the name `WM_PROTOCOLS`. The `protocol` is the
+	/// [atom] identifying the protocol in question, such as the [atom] interned
+	/// for `WM_DELETE_WINDOW` or `WM_TAKE_FOCUS`.
+	///
+	/// The `data` is [`ClientMessageData::I32`] with the `protocol` as the
+	/// first value and the `time` as the second value. The remaining values are
+	/// zero.
+	///
+	/// [ICCCM]: https://x.org/releases/X11R7.7/doc/xorg-docs/icccm/icccm.html#WM_PROTOCOLS_Property
+	/// [atom]: Atom
+	#[allow(
+		clippy::cast_possible_wrap,
+		reason = "`i32` data is only reinterpreted here: the bytes written are the same"
+	)]
+	#[must_use]
+	pub const fn wm_protocols(
+		window: Window, wm_protocols: Atom, protocol: Atom, time: Timestamp,
+	) -> Self {
+		Self {
+			sequence: 0,
+
+			window,
+			r#type: wm_protocols,
+
+			data: ClientMessageData::I32([protocol.unwrap() as i32, time.unwrap() as i32, 0, 0, 0]),
+		}
+	}
+}
+
 /// Detail about which [request] generated a [`MappingChange` event].
 ///
 /// [request]: crate::message::Request
@@ -2429,7 +2627,7 @@ derive_xrb! {
 	/// [`SetModifierMapping`]: super::request::SetModifierMapping
 	/// [`ChangeKeyboardMapping`]: super::request::ChangeKeyboardMapping
 	/// [`SetCursorMapping`]: super::request::SetButtonMapping
-	#[derive(Debug, Derivative, X11Size, Readable, Writable)]
+	#[derive(Debug, Derivative, X11Size, ConstantX11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct MappingChange: Event(34) {
 		/// The [sequence number] associated with the last [request] related
@@ -2464,3 +2662,603 @@ derive_xrb! {
 		[_; ..],
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write, ReadError};
+
+	use super::*;
+	use crate::atom::{PRIMARY, STRING};
+
+	const ROOT: Window = Window::new(0x0000_0100);
+	const WINDOW: Window = Window::new(0x0040_0001);
+	const CHILD: Window = Window::new(0x0040_0002);
+
+	const GEOMETRY: Rectangle = Rectangle::new(Px(-10), Px(20), Px(300), Px(400));
+
+	#[test]
+	fn test_event_code() {
+		assert_eq!(KeyPress::CODE, 2);
+		assert_eq!(Expose::CODE, 12);
+
+		assert!(KeyPress::matches_code(2));
+		// Sent with a `SendEvent` request.
+		assert!(KeyPress::matches_code(0x82));
+		assert!(!KeyPress::matches_code(3));
+
+		match 12 {
+			KeyPress::CODE => panic!("expected `Expose`, found `KeyPress`"),
+			Expose::CODE => {},
+			other => panic!("unexpected event code {other}"),
+		}
+	}
+
+	#[test]
+	fn test_infer_unused_pads_event_to_32_bytes() {
+		// `Focus` ends with `[_; ..]` after only 9 bytes of fields.
+		let focus = Focus {
+			sequence: 1,
+			detail: FocusDetail::Nonlinear,
+			window: WINDOW,
+			grab_mode: FocusGrabMode::Normal,
+		};
+		let unfocus = Unfocus {
+			sequence: 2,
+			detail: FocusDetail::Ancestor,
+			window: CHILD,
+			grab_mode: FocusGrabMode::Grab,
+		};
+
+		assert_eq!(focus.x11_size(), 32);
+		assert_eq!(Focus::X11_SIZE, focus.x11_size());
+
+		let focus_bytes = write(&focus);
+		assert_eq!(focus_bytes.len(), 32);
+
+		let bytes = [focus_bytes, write(&unfocus)].concat();
+		assert_eq!(bytes.len(), 64);
+
+		// Reading the first event must consume exactly 32 bytes so that the next
+		// event in the stream is aligned.
+		let mut buf = &bytes[1..];
+		assert_eq!(Focus::read_from(&mut buf).unwrap(), focus);
+		assert_eq!(buf.len(), 32);
+		assert_eq!(buf[0], Unfocus::CODE);
+
+		let mut buf = &buf[1..];
+		assert_eq!(Unfocus::read_from(&mut buf).unwrap(), unfocus);
+		assert!(buf.is_empty());
+	}
+
+	#[test]
+	fn test_input_events_roundtrip() {
+		assert_roundtrip!(
+			KeyPress {
+				sequence: 1,
+				keycode: Keycode::new(38),
+				time: Timestamp::new(1000),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: Some(CHILD),
+				root_coords: Coords::new(Px(100), Px(200)),
+				event_coords: Coords::new(Px(-5), Px(6)),
+				modifiers: ModifierMask::SHIFT,
+				same_screen: true,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			KeyRelease {
+				sequence: 2,
+				keycode: Keycode::new(38),
+				time: Timestamp::new(1001),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: None,
+				root_coords: Coords::new(Px(100), Px(200)),
+				event_coords: Coords::new(Px(-5), Px(6)),
+				modifiers: ModifierMask::empty(),
+				same_screen: false,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			ButtonPress {
+				sequence: 3,
+				button: Button::PRIMARY,
+				time: Timestamp::new(1002),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: Some(CHILD),
+				root_coords: Coords::new(Px(1), Px(2)),
+				event_coords: Coords::new(Px(3), Px(4)),
+				modifiers: ModifierMask::CONTROL,
+				same_screen: true,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			ButtonRelease {
+				sequence: 4,
+				button: Button::SECONDARY,
+				time: Timestamp::new(1003),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: None,
+				root_coords: Coords::new(Px(1), Px(2)),
+				event_coords: Coords::new(Px(3), Px(4)),
+				modifiers: ModifierMask::BUTTON_3,
+				same_screen: true,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Motion {
+				sequence: 5,
+				notification_type: MotionNotificationType::Hint,
+				time: Timestamp::new(1004),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: Some(CHILD),
+				root_coords: Coords::new(Px(7), Px(8)),
+				event_coords: Coords::new(Px(9), Px(10)),
+				modifiers: ModifierMask::BUTTON_1,
+				same_screen: true,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			MappingChange {
+				sequence: 6,
+				request: MappingRequest::Keyboard,
+				first_keycode: Keycode::new(8),
+				count: 248,
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_crossing_and_focus_events_roundtrip() {
+		assert_roundtrip!(
+			EnterWindow {
+				sequence: 1,
+				detail: EnterLeaveDetail::Nonlinear,
+				time: Timestamp::new(2000),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: Some(CHILD),
+				root_coords: Coords::new(Px(11), Px(12)),
+				event_coords: Coords::new(Px(13), Px(14)),
+				modifiers: ModifierMask::SHIFT,
+				grab_mode: GrabMode::Normal,
+				mask: EnterLeaveMask::FOCUS | EnterLeaveMask::SAME_SCREEN,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			LeaveWindow {
+				sequence: 2,
+				detail: EnterLeaveDetail::Ancestor,
+				time: Timestamp::new(2001),
+				root: ROOT,
+				event_window: WINDOW,
+				child_window: None,
+				root_coords: Coords::new(Px(11), Px(12)),
+				event_coords: Coords::new(Px(13), Px(14)),
+				modifiers: ModifierMask::empty(),
+				grab_mode: GrabMode::Ungrab,
+				mask: EnterLeaveMask::SAME_SCREEN,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Focus {
+				sequence: 3,
+				detail: FocusDetail::Cursor,
+				window: WINDOW,
+				grab_mode: FocusGrabMode::WhileGrabbed,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Unfocus {
+				sequence: 4,
+				detail: FocusDetail::Descendent,
+				window: WINDOW,
+				grab_mode: FocusGrabMode::Normal,
+			},
+			skip = 1,
+		);
+
+		let mut keys = [0; 31];
+		keys[3] = 0b0100_0001;
+		assert_roundtrip!(KeyboardState { keys }, skip = 1);
+	}
+
+	#[test]
+	fn test_expose_try_from_frame() {
+		let mut frame = [0; 32];
+		frame[..18].copy_from_slice(&[
+			12, 0, 0, 7, // code, unused, sequence
+			0x00, 0x40, 0x00, 0x01, // window
+			0, 0, 0, 10, 0, 20, 0, 30, // region
+			0, 2, // count
+		]);
+
+		let expose = Expose::try_from(&frame).unwrap();
+
+		assert_eq!(expose.sequence, 7);
+		assert_eq!(
+			expose,
+			Expose {
+				sequence: 7,
+				window: WINDOW,
+				region: Region::new(Px(0), Px(10), Px(20), Px(30)),
+				count: 2,
+			},
+		);
+
+		// Sent with a `SendEvent` request.
+		frame[0] = 0x80 | Expose::CODE;
+		assert!(Expose::try_from(&frame).is_ok());
+	}
+
+	#[test]
+	fn test_expose_try_from_frame_wrong_code() {
+		let mut frame = [0; 32];
+		frame[0] = GraphicsExposure::CODE;
+
+		assert!(matches!(
+			Expose::try_from(&frame),
+			Err(ReadError::UnrecognizedDiscriminant(13)),
+		));
+	}
+
+	#[test]
+	fn test_exposure_events_roundtrip() {
+		assert_roundtrip!(
+			Expose {
+				sequence: 1,
+				window: WINDOW,
+				region: Region::new(Px(0), Px(10), Px(20), Px(30)),
+				count: 2,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			GraphicsExposure {
+				sequence: 2,
+				drawable: Drawable::new(0x0040_0003),
+				region: Region::new(Px(5), Px(6), Px(7), Px(8)),
+				minor_opcode: 0,
+				count: 0,
+				major_opcode: 62,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			NoExposure {
+				sequence: 3,
+				drawable: Drawable::new(0x0040_0003),
+				minor_opcode: 0,
+				major_opcode: 63,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Visibility {
+				sequence: 4,
+				window: WINDOW,
+				visibility: VisibilityState::PartiallyObscured,
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_window_events_roundtrip() {
+		assert_roundtrip!(
+			Create {
+				sequence: 1,
+				parent: ROOT,
+				window: WINDOW,
+				geometry: GEOMETRY,
+				border_width: Px(2),
+				override_redirect: false,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Destroy {
+				sequence: 2,
+				event_window: ROOT,
+				window: WINDOW,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Unmap {
+				sequence: 3,
+				event_window: ROOT,
+				window: WINDOW,
+				from_configure: true,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Map {
+				sequence: 4,
+				event_window: ROOT,
+				window: WINDOW,
+				override_redirect: true,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			MapWindowRequest {
+				sequence: 5,
+				parent: ROOT,
+				window: WINDOW,
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_configuration_events_roundtrip() {
+		assert_roundtrip!(
+			Reparent {
+				sequence: 6,
+				event_window: WINDOW,
+				window: WINDOW,
+				new_parent: CHILD,
+				coords: Coords::new(Px(-1), Px(1)),
+				override_redirect: false,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Configure {
+				sequence: 7,
+				event_window: ROOT,
+				window: WINDOW,
+				sibling_below: Some(CHILD),
+				geometry: GEOMETRY,
+				border_width: Px(0),
+				override_redirect: false,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			ConfigureWindowRequest {
+				sequence: 8,
+				stack_mode: StackMode::Below,
+				parent: ROOT,
+				window: WINDOW,
+				sibling: None,
+				geometry: GEOMETRY,
+				mask: WindowConfigMask::X | WindowConfigMask::Y,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Gravity {
+				sequence: 9,
+				event_window: ROOT,
+				window: WINDOW,
+				coords: Coords::new(Px(50), Px(60)),
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			ResizeRequest {
+				sequence: 10,
+				window: WINDOW,
+				width: Px(640),
+				height: Px(480),
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Circulate {
+				sequence: 11,
+				event_window: ROOT,
+				window: WINDOW,
+				placement: Placement::Bottom,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			CirculateWindowRequest {
+				sequence: 12,
+				parent: ROOT,
+				window: WINDOW,
+				placement: Placement::Top,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Colormap {
+				sequence: 13,
+				window: WINDOW,
+				colormap: Some(crate::Colormap::new(0x0020_0001)),
+				detail: ColormapDetail::InstalledOrUninstalled,
+				state: ColormapState::Installed,
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_property_and_selection_events_roundtrip() {
+		assert_roundtrip!(
+			Property {
+				sequence: 1,
+				window: WINDOW,
+				property: Atom::new(39),
+				time: Timestamp::new(3000),
+				change: PropertyChange::Deleted,
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			SelectionClear {
+				sequence: 2,
+				time: Timestamp::new(3001),
+				owner: WINDOW,
+				selection: Atom::new(1),
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			ConvertSelectionRequest {
+				sequence: 3,
+				time: CurrentableTime::CurrentTime,
+				owner: WINDOW,
+				requester: CHILD,
+				selection: Atom::new(1),
+				target_type: Atom::new(31),
+				property: Some(Atom::new(300)),
+			},
+			skip = 1,
+		);
+		assert_roundtrip!(
+			Selection {
+				sequence: 4,
+				time: CurrentableTime::Other(Timestamp::new(3002)),
+				requester: CHILD,
+				selection: Atom::new(1),
+				target_type: Atom::new(31),
+				property: None,
+			},
+			skip = 1,
+		);
+
+		for data in [
+			ClientMessageData::I8([-1; 20]),
+			ClientMessageData::I16([0x0102; 10]),
+			ClientMessageData::I32([1, -2, 3, -4, 5]),
+		] {
+			assert_roundtrip!(
+				ClientMessage {
+					sequence: 5,
+					window: WINDOW,
+					r#type: Atom::new(300),
+					data,
+				},
+				skip = 1,
+			);
+		}
+	}
+
+	#[test]
+	fn test_keyboard_state_is_pressed() {
+		let mut keys = [0; 31];
+		// Keycode 38 is at index `38 / 8 - 1 = 3`, bit `38 % 8 = 6`.
+		keys[3] = 1 << 6;
+
+		let state = KeyboardState { keys };
+
+		assert!(state.is_pressed(Keycode::new(38)));
+		assert!(!state.is_pressed(Keycode::new(39)));
+		assert!(!state.is_pressed(Keycode::new(6)));
+		assert_eq!(state.sequence(), None);
+
+		let bytes = write(&state);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[0], KeyboardState::CODE);
+		// The `keys` start at byte 1 - there is no sequence number.
+		assert_eq!(bytes[1..], keys);
+
+		let read = KeyboardState::read_from(&mut &bytes[1..]).unwrap();
+		assert_eq!(read.keys, keys);
+	}
+
+	#[test]
+	fn test_convert_selection_request_reply() {
+		let request = ConvertSelectionRequest {
+			sequence: 0,
+
+			time: CurrentableTime::Other(Timestamp::new(1000)),
+
+			owner: WINDOW,
+			requester: CHILD,
+
+			selection: PRIMARY,
+			target_type: STRING,
+			property: None,
+		};
+
+		assert_eq!(request.property_or_target(), STRING);
+
+		let converted = request.reply(Some(request.property_or_target()));
+
+		assert_eq!(converted.time, request.time);
+		assert_eq!(converted.requester, CHILD);
+		assert_eq!(converted.selection, PRIMARY);
+		assert_eq!(converted.target_type, STRING);
+		assert_eq!(converted.property, Some(STRING));
+
+		assert_eq!(request.reply(None).property, None);
+	}
+
+	#[test]
+	fn test_client_message_wm_protocols() {
+		let wm_protocols = Atom::new(300);
+		let wm_delete_window = Atom::new(301);
+
+		let message = ClientMessage::wm_protocols(
+			Window::new(1),
+			wm_protocols,
+			wm_delete_window,
+			Timestamp::new(u32::MAX),
+		);
+
+		assert_eq!(message.r#type, wm_protocols);
+		assert_eq!(message.data, ClientMessageData::I32([301, -1, 0, 0, 0]));
+	}
+
+	#[test]
+	fn test_client_message_sequence() {
+		let message = ClientMessage {
+			sequence: 0x1234,
+
+			window: Window::new(0x0506_0708),
+			r#type: Atom::new(0x090a_0b0c),
+
+			data: ClientMessageData::I32([0; 5]),
+		};
+
+		let bytes = write(&message);
+
+		assert_eq!(bytes.len(), 32);
+
+		// Event code.
+		assert_eq!(bytes[0], 33);
+		// Metabyte: the format.
+		assert_eq!(bytes[1], 32);
+		// Sequence number.
+		assert_eq!(bytes[2..4], [0x12, 0x34]);
+		// `window` and `r#type`.
+		assert_eq!(bytes[4..12], [5, 6, 7, 8, 9, 10, 11, 12]);
+
+		// The event code has already been read when an event is read.
+		let read = ClientMessage::read_from(&mut &bytes[1..]).unwrap();
+		assert_eq!(read.sequence, 0x1234);
+	}
+
+	#[test]
+	fn test_client_message_data_as_i32() {
+		let mut values = [0; 20];
+		values[..8].copy_from_slice(&[0, 0, 1, 44, -1, -1, -1, -2]);
+
+		let data = ClientMessageData::I8(values);
+
+		assert_eq!(data.format(), ClientMessageFormat::I8);
+		assert_eq!(data.as_i32(), [300, -2, 0, 0, 0]);
+		assert_eq!(data.as_i16()[..4], [0, 300, -1, -2]);
+		assert_eq!(data.as_i8(), values);
+	}
+}
@@ -17,6 +17,7 @@
 extern crate self as xrb;
 
 use derivative::Derivative;
+use std::iter;
 use xrbk::{Buf, BufMut, ConstantX11Size, ReadResult, Readable, Writable, WriteResult, X11Size};
 
 use xrbk_macro::derive_xrb;
@@ -125,9 +126,15 @@ derive_xrb! {
 		#[derivative(Hash = "ignore", PartialEq = "ignore")]
 		pub sequence: u16,
 
+		/// The [`ColorId`] referring to the `actual_color`.
+		#[doc(alias = "pixel")]
+		pub color_id: ColorId,
+
 		/// The ideal or 'true' color which the name represents.
+		#[doc(alias = "exact")]
 		pub ideal_color: RgbColor,
 		/// The closest color that the display was able to provide.
+		#[doc(alias = "visual")]
 		pub actual_color: RgbColor,
 		[_; ..],
 	}
@@ -243,6 +250,82 @@ derive_xrb! {
 	}
 }
 
+/// Returns every subset of the bits set in the given `mask`, starting with `0`
+/// and ending with the `mask` itself.
+fn mask_subsets(mask: u32) -> impl Iterator<Item = u32> {
+	let mut next = Some(0);
+
+	iter::from_fn(move || {
+		let subset = next?;
+		next = (subset != mask).then(|| subset.wrapping_sub(mask) & mask);
+
+		Some(subset)
+	})
+}
+
+/// Returns every [`ColorId`] formed by combining each of the `colors` with
+/// every subset of the bits set in the `mask`.
+fn combine_colors(colors: &[ColorId], mask: u32) -> impl Iterator<Item = ColorId> + '_ {
+	colors.iter().flat_map(move |color| {
+		let color = u32::from(*color);
+
+		mask_subsets(mask).map(move |subset| ColorId::new(color | subset))
+	})
+}
+
+impl AllocateColorCells {
+	/// Returns each of the `colors` paired with the `plane_masks` which it is
+	/// combined with.
+	///
+	/// Every one of the `plane_masks` applies to every one of the `colors`: the
+	/// [`AllocateColorCells` request]'s `color_count` determines the number of
+	/// `colors`, and its `plane_count` determines the number of
+	/// `plane_masks`.
+	///
+	/// [`AllocateColorCells` request]: request::AllocateColorCells
+	pub fn cells(&self) -> impl Iterator<Item = (ColorId, &[u32])> {
+		self.colors
+			.iter()
+			.map(|color| (*color, self.plane_masks.as_slice()))
+	}
+
+	/// Returns every [`ColorId`] which was allocated.
+	///
+	/// Each of the `colors` is combined (bitwise OR) with every subset of the
+	/// `plane_masks`, so there are
+	/// <code>color_count * 2<sup>plane_count</sup></code> allocated
+	/// [`ColorId`s](ColorId).
+	pub fn entries(&self) -> impl Iterator<Item = ColorId> + '_ {
+		let mask = self.plane_masks.iter().fold(0, |mask, plane| mask | plane);
+
+		combine_colors(&self.colors, mask)
+	}
+}
+
+impl AllocateColorPlanes {
+	/// Returns the union of the `red_plane_mask`, `green_plane_mask`, and
+	/// `blue_plane_mask`.
+	#[must_use]
+	pub const fn plane_mask(&self) -> u32 {
+		self.red_plane_mask | self.green_plane_mask | self.blue_plane_mask
+	}
+
+	/// Returns every [`ColorId`] which was allocated.
+	///
+	/// Each of the `colors` is combined (bitwise OR) with every subset of the
+	/// [`plane_mask`], so there are
+	/// <code>color_count * 2<sup>plane_count</sup></code> allocated
+	/// [`ColorId`s](ColorId), where `plane_count` is the sum of the
+	/// [`AllocateColorPlanes` request]'s `red_plane_count`,
+	/// `green_plane_count`, and `blue_plane_count`.
+	///
+	/// [`plane_mask`]: AllocateColorPlanes::plane_mask
+	/// [`AllocateColorPlanes` request]: request::AllocateColorPlanes
+	pub fn entries(&self) -> impl Iterator<Item = ColorId> + '_ {
+		combine_colors(&self.colors, self.plane_mask())
+	}
+}
+
 /// The [reply] to a [`QueryColors` request].
 ///
 /// [reply]: Reply
@@ -363,12 +446,156 @@ derive_xrb! {
 		/// The ideal [RGB values] of the color.
 		///
 		/// [RGB values]: RgbColor
+		#[doc(alias = "exact")]
 		pub ideal_color: RgbColor,
 		/// The closest [RGB values] to the `ideal_color` that the display could
 		/// provide.
 		///
 		/// [RGB values]: RgbColor
+		#[doc(alias = "visual")]
 		pub actual_color: RgbColor,
 		[_; ..],
 	}
 }
+
+impl GetNamedColor {
+	/// The exact [RGB values] of the named color.
+	///
+	/// This is the same as the `ideal_color`.
+	///
+	/// [RGB values]: RgbColor
+	#[must_use]
+	pub const fn exact(&self) -> RgbColor {
+		self.ideal_color
+	}
+
+	/// The closest [RGB values] to the [`exact`] color that the display could
+	/// provide.
+	///
+	/// This is the same as the `actual_color`.
+	///
+	/// [RGB values]: RgbColor
+	/// [`exact`]: GetNamedColor::exact
+	#[must_use]
+	pub const fn visual(&self) -> RgbColor {
+		self.actual_color
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+	use crate::message::{Reply, Request};
+
+	#[test]
+	fn test_allocate_color() {
+		let reply = AllocateColor {
+			sequence: 4,
+			actual_color: RgbColor(0xff00, 0x8000, 0x0000),
+			color_id: ColorId::new(0x0012_3456),
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[16..20], [0x00, 0x12, 0x34, 0x56]);
+	}
+
+	#[test]
+	fn test_allocate_named_color() {
+		let reply = AllocateNamedColor {
+			sequence: 5,
+			color_id: ColorId::new(0x0012_3456),
+			ideal_color: RgbColor(0xffff, 0x0000, 0x0000),
+			actual_color: RgbColor(0xff00, 0x0000, 0x0000),
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(
+			bytes[8..20],
+			[0x00, 0x12, 0x34, 0x56, 0xff, 0xff, 0, 0, 0, 0, 0xff, 0x00],
+		);
+	}
+
+	#[test]
+	fn test_get_named_color() {
+		let reply = GetNamedColor {
+			sequence: 6,
+			ideal_color: RgbColor(0x1234, 0x5678, 0x9abc),
+			actual_color: RgbColor(0x1200, 0x5600, 0x9a00),
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		assert_eq!(reply.exact(), RgbColor(0x1234, 0x5678, 0x9abc));
+		assert_eq!(reply.visual(), RgbColor(0x1200, 0x5600, 0x9a00));
+	}
+
+	#[test]
+	fn test_list_installed_colormaps() {
+		let reply = ListInstalledColormaps {
+			sequence: 3,
+			colormaps: vec![Colormap::new(0x20), Colormap::new(0x0040_0003)],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+		assert_eq!(
+			ListInstalledColormaps::FOR_MAJOR_OPCODE,
+			request::ListInstalledColormaps::MAJOR_OPCODE,
+		);
+
+		let bytes = write(&reply);
+
+		// Header, then two colormaps.
+		assert_eq!(bytes.len(), 32 + 8);
+		assert_eq!(bytes[4..10], [0, 0, 0, 2, 0, 2]);
+	}
+
+	#[test]
+	fn test_allocate_color_cells() {
+		let reply = AllocateColorCells {
+			sequence: 1,
+			colors: vec![ColorId::new(0x00), ColorId::new(0x10)],
+			plane_masks: vec![0x01, 0x04],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let cells: Vec<_> = reply.cells().collect();
+		assert_eq!(
+			cells,
+			[
+				(ColorId::new(0x00), &[0x01, 0x04][..]),
+				(ColorId::new(0x10), &[0x01, 0x04][..]),
+			]
+		);
+
+		let entries: Vec<_> = reply.entries().map(u32::from).collect();
+		assert_eq!(entries, [0x00, 0x01, 0x04, 0x05, 0x10, 0x11, 0x14, 0x15]);
+	}
+
+	#[test]
+	fn test_allocate_color_planes() {
+		let reply = AllocateColorPlanes {
+			sequence: 2,
+			red_plane_mask: 0x0001_0000,
+			green_plane_mask: 0x0000_0100,
+			blue_plane_mask: 0x0000_0001,
+			colors: vec![ColorId::new(0x0080_0000)],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		assert_eq!(reply.plane_mask(), 0x0001_0101);
+		assert_eq!(reply.entries().count(), 8);
+		assert_eq!(reply.entries().last(), Some(ColorId::new(0x0081_0101)));
+	}
+}
@@ -354,6 +354,34 @@ derive_xrb! {
 	}
 }
 
+impl ListFonts {
+	/// Returns an iterator over the `names` of the fonts, decoded from
+	/// ISO Latin-1.
+	pub fn iter_str(&self) -> impl Iterator<Item = String> + '_ {
+		self.names
+			.iter()
+			.map(|name| name.string().chars().collect())
+	}
+}
+
+impl QueryFont {
+	/// Returns the [`CharacterInfo`] at the given `index` in
+	/// `character_infos`, if there is one.
+	#[doc(alias = "char_info")]
+	#[must_use]
+	pub fn character_info(&self, index: usize) -> Option<&CharacterInfo> {
+		self.character_infos.get(index)
+	}
+
+	/// Returns the [`FontProperty`] with the given `name`, if there is one.
+	#[must_use]
+	pub fn property(&self, name: Atom) -> Option<&FontProperty> {
+		self.properties
+			.iter()
+			.find(|property| property.name == name)
+	}
+}
+
 /// The [reply] to a [`ListFontsWithInfo` request].
 ///
 /// The [`ListFontsWithInfo` request] is unique in that it has a series of
@@ -830,3 +858,87 @@ derive_xrb! {
 		[_; directories => pad(directories)],
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	const fn character_info(width: i16) -> CharacterInfo {
+		CharacterInfo {
+			left_side_bearing: 0,
+			right_side_bearing: width,
+			width,
+			ascent: 10,
+			descent: 2,
+			attributes: 0,
+		}
+	}
+
+	#[test]
+	fn test_list_fonts_round_trip() {
+		let reply = ListFonts {
+			sequence: 2,
+			names: vec![
+				LengthString8::from(String8::try_from("fixed").unwrap()),
+				LengthString8::from(String8::try_from("cursor").unwrap()),
+			],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		// Header, then 13 bytes of names padded to 16.
+		assert_eq!(bytes.len(), 32 + 16);
+		assert_eq!(bytes[8..10], [0, 2]);
+		assert_eq!(bytes[32..38], [5, b'f', b'i', b'x', b'e', b'd']);
+
+		assert_eq!(reply.iter_str().collect::<Vec<_>>(), ["fixed", "cursor"]);
+	}
+
+	#[test]
+	fn test_query_font_round_trip() {
+		let weight = Atom::new(0x0000_0040);
+
+		let reply = QueryFont {
+			sequence: 1,
+			min_bounds: character_info(4),
+			max_bounds: character_info(8),
+			first_character_or_min_minor_index: 32,
+			last_character_or_max_minor_index: 33,
+			fallback_character: 32,
+			draw_direction: DrawDirection::LeftToRight,
+			min_major_index: 0,
+			max_major_index: 0,
+			all_characters_exist: true,
+			font_ascent: 10,
+			font_descent: 2,
+			properties: vec![FontProperty {
+				name: weight,
+				value: [0, 0, 0, 10],
+			}],
+			character_infos: vec![character_info(4), character_info(8)],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		// Header, one property, then two character infos.
+		assert_eq!(bytes.len(), 60 + 8 + 2 * 12);
+		// `properties_len` and `character_infos_len`.
+		assert_eq!(bytes[46..48], [0, 1]);
+		assert_eq!(bytes[56..60], [0, 0, 0, 2]);
+
+		assert_eq!(reply.character_info(1), Some(&character_info(8)));
+		assert_eq!(reply.character_info(2), None);
+
+		assert_eq!(
+			reply.property(weight).map(|property| property.value),
+			Some([0, 0, 0, 10]),
+		);
+		assert_eq!(reply.property(Atom::new(0x0000_0041)), None);
+	}
+}
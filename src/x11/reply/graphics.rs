@@ -24,7 +24,14 @@ use derivative::Derivative;
 use xrbk::pad;
 use xrbk_macro::derive_xrb;
 
-use crate::{message::Reply, visual::VisualId, x11::request};
+use crate::{
+	connection::{ConnectionSuccess, ImageEndianness},
+	message::Reply,
+	unit::Px,
+	visual::VisualId,
+	x11::request::{self, CaptureImageFormat},
+	Dimensions,
+};
 
 derive_xrb! {
 	/// The [reply] to a [`CaptureImage` request].
@@ -70,3 +77,323 @@ derive_xrb! {
 		[_; data => pad(data)],
 	}
 }
+
+impl CaptureImage {
+	/// Returns an iterator over the values of each pixel in the image.
+	///
+	/// Pixels are returned in rows from left to right, starting with the top
+	/// row.
+	///
+	/// The `format` and `dimensions` must be those given in the
+	/// [`CaptureImage` request] which generated this [reply]. The layout of the
+	/// image data is determined by the image and bitmap formats given in the
+	/// `connection` setup.
+	///
+	/// For [`CaptureImageFormat::XyPixmap`], the bits of each pixel value are
+	/// taken from each transmitted bit plane, most significant first. For
+	/// [`CaptureImageFormat::Zpixmap`], each pixel value is read directly.
+	///
+	/// Returns [`None`] if `connection` does not contain a [pixmap format]
+	/// for this image's `depth`, if the image or bitmap formats in
+	/// `connection` have a scanline unit or padding of zero, or if `data` is
+	/// too short for the given `format` and `dimensions`.
+	///
+	/// [`CaptureImage` request]: request::CaptureImage
+	/// [reply]: Reply
+	///
+	/// [pixmap format]: crate::visual::Format
+	#[must_use]
+	pub fn pixels<'a>(
+		&'a self, format: CaptureImageFormat, dimensions: Dimensions,
+		connection: &ConnectionSuccess,
+	) -> Option<impl Iterator<Item = u32> + 'a> {
+		let Dimensions {
+			width: Px(width),
+			height: Px(height),
+		} = dimensions;
+
+		let image_byte_order = connection.image_byte_order;
+		let bitmap_bit_order = connection.bitmap_format_bit_order;
+		let bitmap_unit = usize::from(connection.bitmap_format_scanline_unit / 8);
+
+		if bitmap_unit == 0 {
+			return None;
+		}
+
+		let pixmap_format = connection.pixmap_format(self.depth)?;
+		let bits_per_pixel = usize::from(pixmap_format.bits_per_pixel);
+
+		let scanline_len = match format {
+			CaptureImageFormat::XyPixmap => connection.bitmap_scanline_len(width)?,
+			CaptureImageFormat::Zpixmap => pixmap_format.zpixmap_scanline_len(width)?,
+		};
+		let plane_len = scanline_len.checked_mul(usize::from(height))?;
+
+		let plane_count = match format {
+			// Each bit plane is transmitted one after the other.
+			CaptureImageFormat::XyPixmap => self.data.len().checked_div(plane_len).unwrap_or(0),
+			CaptureImageFormat::Zpixmap => 1,
+		};
+
+		if self.data.len() < plane_count * plane_len {
+			return None;
+		}
+
+		let data = &self.data;
+
+		Some((0..usize::from(height)).flat_map(move |y| {
+			(0..usize::from(width)).map(move |x| match format {
+				CaptureImageFormat::XyPixmap => (0..plane_count).fold(0, |pixel, plane| {
+					let start = (plane * plane_len) + (y * scanline_len);
+					let scanline = &data[start..(start + scanline_len)];
+
+					let bit =
+						read_bit(scanline, x, bitmap_unit, image_byte_order, bitmap_bit_order);
+
+					(pixel << 1) | u32::from(bit)
+				}),
+
+				CaptureImageFormat::Zpixmap => {
+					let start = y * scanline_len;
+					let scanline = &data[start..(start + scanline_len)];
+
+					read_zpixmap_pixel(
+						scanline,
+						x,
+						bits_per_pixel,
+						bitmap_unit,
+						image_byte_order,
+						bitmap_bit_order,
+					)
+				},
+			})
+		}))
+	}
+}
+
+/// Reads the bit at the given `index` of a bitmap scanline.
+///
+/// The scanline is made up of units of `unit` bytes. The significance of the
+/// bits within each unit is determined by `bit_order`, and the order of the
+/// bytes within each unit is determined by `byte_order`.
+fn read_bit(
+	scanline: &[u8], index: usize, unit: usize, byte_order: ImageEndianness,
+	bit_order: ImageEndianness,
+) -> bool {
+	let unit_bits = unit * 8;
+	let (unit_index, bit_index) = (index / unit_bits, index % unit_bits);
+
+	// The significance of the bit within its unit.
+	let significance = match bit_order {
+		ImageEndianness::LittleEndian => bit_index,
+		ImageEndianness::BigEndian => unit_bits - 1 - bit_index,
+	};
+
+	let byte_index = match byte_order {
+		ImageEndianness::LittleEndian => significance / 8,
+		ImageEndianness::BigEndian => unit - 1 - (significance / 8),
+	};
+
+	(scanline[(unit_index * unit) + byte_index] >> (significance % 8)) & 1 == 1
+}
+
+/// Reads the pixel at the given `index` of a Z format scanline.
+fn read_zpixmap_pixel(
+	scanline: &[u8], index: usize, bits_per_pixel: usize, bitmap_unit: usize,
+	byte_order: ImageEndianness, bit_order: ImageEndianness,
+) -> u32 {
+	match bits_per_pixel {
+		1 => u32::from(read_bit(
+			scanline,
+			index,
+			bitmap_unit,
+			byte_order,
+			bit_order,
+		)),
+
+		4 => {
+			let byte = scanline[index / 2];
+
+			// The order of the nibbles within each byte is determined by the
+			// image byte order.
+			let (first, second) = match byte_order {
+				ImageEndianness::LittleEndian => (byte & 0x0f, byte >> 4),
+				ImageEndianness::BigEndian => (byte >> 4, byte & 0x0f),
+			};
+
+			u32::from(if index % 2 == 1 { second } else { first })
+		},
+
+		bits_per_pixel => {
+			let bytes_per_pixel = bits_per_pixel / 8;
+			let start = index * bytes_per_pixel;
+			let bytes = &scanline[start..(start + bytes_per_pixel)];
+
+			match byte_order {
+				ImageEndianness::LittleEndian => bytes
+					.iter()
+					.rev()
+					.fold(0, |pixel, byte| (pixel << 8) | u32::from(*byte)),
+				ImageEndianness::BigEndian => bytes
+					.iter()
+					.fold(0, |pixel, byte| (pixel << 8) | u32::from(*byte)),
+			}
+		},
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{test_support::write, ReadError, Readable};
+
+	use super::*;
+
+	#[test]
+	fn test_capture_image_zpixmap_round_trip() {
+		let reply = CaptureImage {
+			sequence: 3,
+			depth: 24,
+			visual: Some(VisualId::new(0x21)),
+			data: (0..16).collect(),
+		};
+
+		let bytes = write(&reply);
+
+		// 32 bytes followed by 2x2 pixels of 4 bytes each.
+		assert_eq!(bytes.len(), 32 + 16);
+		assert_eq!(bytes[..12], [1, 24, 0, 3, 0, 0, 0, 4, 0, 0, 0, 0x21]);
+		assert_eq!(bytes[32..], (0..16).collect::<Vec<u8>>());
+
+		assert_eq!(CaptureImage::read_from(&mut &bytes[1..]).unwrap(), reply);
+	}
+
+	#[test]
+	fn test_capture_image_reads_data_tail() {
+		let data: Vec<u8> = (0..100).collect();
+
+		let mut bytes = vec![1, 8, 0, 5, 0, 0, 0, 25];
+		bytes.extend([0; 24]);
+		bytes.extend(&data);
+
+		let reply = CaptureImage::read_from(&mut &bytes[1..]).unwrap();
+
+		assert_eq!(reply.depth, 8);
+		assert_eq!(reply.visual, None);
+		assert_eq!(reply.data, data);
+	}
+
+	#[test]
+	fn test_capture_image_truncated_data() {
+		// The length claims 100 bytes of data, but only 10 are present.
+		let mut bytes = vec![1, 8, 0, 5, 0, 0, 0, 25];
+		bytes.extend([0; 24]);
+		bytes.extend([0; 10]);
+
+		assert!(matches!(
+			CaptureImage::read_from(&mut &bytes[1..]),
+			Err(ReadError::UnexpectedEnd {
+				expected: 100,
+				remaining: 10,
+			}),
+		));
+	}
+
+	#[test]
+	fn test_zpixmap_pixels_depth_24() {
+		let reply = CaptureImage {
+			sequence: 1,
+			depth: 24,
+			visual: None,
+			data: vec![
+				// (0, 0)
+				0x11, 0x22, 0x33, 0x00, //
+				// (1, 0)
+				0xff, 0x00, 0x00, 0x00, //
+				// (0, 1)
+				0x00, 0xff, 0x00, 0x00, //
+				// (1, 1)
+				0x00, 0x00, 0xff, 0x00,
+			],
+		};
+
+		let pixels: Vec<u32> = reply
+			.pixels(
+				CaptureImageFormat::Zpixmap,
+				Dimensions::new(Px(2), Px(2)),
+				&ConnectionSuccess::test_setup(ImageEndianness::LittleEndian),
+			)
+			.unwrap()
+			.collect();
+
+		assert_eq!(pixels, [0x0033_2211, 0x0000_00ff, 0x0000_ff00, 0x00ff_0000]);
+
+		let pixels: Vec<u32> = reply
+			.pixels(
+				CaptureImageFormat::Zpixmap,
+				Dimensions::new(Px(2), Px(2)),
+				&ConnectionSuccess::test_setup(ImageEndianness::BigEndian),
+			)
+			.unwrap()
+			.collect();
+
+		assert_eq!(pixels, [0x1122_3300, 0xff00_0000, 0x00ff_0000, 0x0000_ff00]);
+	}
+
+	#[test]
+	fn test_pixels_unknown_depth() {
+		let reply = CaptureImage {
+			sequence: 1,
+			depth: 8,
+			visual: None,
+			data: vec![0; 4],
+		};
+
+		assert!(reply
+			.pixels(
+				CaptureImageFormat::Zpixmap,
+				Dimensions::new(Px(1), Px(1)),
+				&ConnectionSuccess::test_setup(ImageEndianness::LittleEndian),
+			)
+			.is_none());
+	}
+
+	#[test]
+	fn test_pixels_short_data() {
+		let reply = CaptureImage {
+			sequence: 1,
+			depth: 24,
+			visual: None,
+			// One pixel short of a 2x2 image.
+			data: vec![0; 12],
+		};
+
+		assert!(reply
+			.pixels(
+				CaptureImageFormat::Zpixmap,
+				Dimensions::new(Px(2), Px(2)),
+				&ConnectionSuccess::test_setup(ImageEndianness::LittleEndian),
+			)
+			.is_none());
+	}
+
+	#[test]
+	fn test_pixels_zero_scanline_pad() {
+		let reply = CaptureImage {
+			sequence: 1,
+			depth: 24,
+			visual: None,
+			data: vec![0; 4],
+		};
+
+		let mut connection = ConnectionSuccess::test_setup(ImageEndianness::LittleEndian);
+		connection.bitmap_format_scanline_padding = 0;
+
+		assert!(reply
+			.pixels(
+				CaptureImageFormat::XyPixmap,
+				Dimensions::new(Px(1), Px(1)),
+				&connection,
+			)
+			.is_none());
+	}
+}
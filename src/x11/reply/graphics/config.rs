@@ -43,3 +43,37 @@ derive_xrb! {
 		[_; ..],
 	}
 }
+
+impl QueryIdealDimensions {
+	/// Returns the `ideal_dimensions` for the requested [`DimensionClass`].
+	///
+	/// [`DimensionClass`]: request::DimensionClass
+	#[must_use]
+	pub const fn dimensions(&self) -> Dimensions {
+		self.ideal_dimensions
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+	use crate::unit::Px;
+
+	#[test]
+	fn test_query_ideal_dimensions_round_trip() {
+		let reply = QueryIdealDimensions {
+			sequence: 1,
+			ideal_dimensions: Dimensions::new(Px(32), Px(32)),
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+		assert_eq!(reply.dimensions(), Dimensions::new(Px(32), Px(32)));
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[8..12], [0, 32, 0, 32]);
+	}
+}
@@ -18,7 +18,18 @@ extern crate self as xrb;
 
 use array_init::array_init;
 use derivative::Derivative;
-use xrbk::{Buf, BufMut, ConstantX11Size, ReadResult, Readable, Writable, WriteResult, X11Size};
+use xrbk::{
+	pad,
+	Buf,
+	BufMut,
+	ConstantX11Size,
+	ReadResult,
+	Readable,
+	ReadableWithContext,
+	Writable,
+	WriteResult,
+	X11Size,
+};
 
 use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
 
@@ -111,7 +122,13 @@ derive_xrb! {
 	/// [reply]: Reply
 	///
 	/// [`QueryCursorLocation` request]: request::QueryCursorLocation
-	#[doc(alias("QueryPointer, QueryCursor, GetCursorPos, GetCursorLocation"))]
+	#[doc(alias(
+		"QueryPointer",
+		"QueryPointerReply",
+		"QueryCursor",
+		"GetCursorPos",
+		"GetCursorLocation"
+	))]
 	#[derive(Derivative, Debug, X11Size, Readable, Writable)]
 	#[derivative(Hash, PartialEq, Eq)]
 	pub struct QueryCursorLocation: Reply for request::QueryCursorLocation {
@@ -152,15 +169,18 @@ derive_xrb! {
 		/// `root` [window].
 		///
 		/// [window]: Window
+		#[doc(alias("root_x", "root_y"))]
 		pub root_coords: Coords,
 		/// The coordinates of the cursor relative to the top-left corner of the
 		/// given `target` [window].
 		///
 		/// [window]: Window
 		// TODO: should always be [`None`] if `same_screen` is false
+		#[doc(alias("win_x", "win_y", "win_coords"))]
 		pub target_coords: Coords,
 
 		/// The currently held mouse buttons and modifier keys.
+		#[doc(alias = "mask")]
 		pub modifiers: ModifierMask,
 		[_; ..],
 	}
@@ -173,8 +193,9 @@ derive_xrb! {
 /// [time]: Timestamp
 ///
 /// [`GetMotionHistory` reply]: GetMotionHistory
+#[doc(alias("TimeCoord", "TimeCoords"))]
 #[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
-pub struct TimeCoords {
+pub struct MotionEvent {
 	/// The [time] at which the cursor was at the `coords`.
 	///
 	/// [time]: Timestamp
@@ -206,17 +227,18 @@ derive_xrb! {
 		#[derivative(Hash = "ignore", PartialEq = "ignore")]
 		pub sequence: u16,
 
-		// The length of `motion_history`.
+		// The length of `events`.
 		#[allow(clippy::cast_possible_truncation)]
-		let motion_history_len: u32 = motion_history => motion_history.len() as u32,
+		let events_len: u32 = events => events.len() as u32,
 		[_; 20],
 
 		/// The recorded cursor motion between the `start` and `end` times
 		/// (inclusive) for the given `target` [window].
 		///
 		/// [window]: Window
-		#[context(motion_history_len => *motion_history_len as usize)]
-		pub motion_history: Vec<TimeCoords>,
+		#[doc(alias("motion_history", "motion_events"))]
+		#[context(events_len => *events_len as usize)]
+		pub events: Vec<MotionEvent>,
 	}
 
 	/// The [reply] to a [`ConvertCoordinates` request].
@@ -336,6 +358,40 @@ derive_xrb! {
 	}
 }
 
+impl QueryCursorLocation {
+	/// Returns the location of the cursor relative to the top-left corner of
+	/// the `root` [window].
+	///
+	/// Unlike the `target_coords`, this is meaningful even if the cursor is not
+	/// on the `same_screen` as the `target` [window].
+	///
+	/// [window]: Window
+	#[must_use]
+	pub const fn cursor_location(&self) -> Coords {
+		self.root_coords
+	}
+}
+
+impl ConvertCoordinates {
+	/// Returns the converted `output_coords` and the mapped `child` containing
+	/// them, or [`None`] if the `original` and `output` [windows] are not on
+	/// the same [screen].
+	///
+	/// When `same_screen` is `false`, the X server sends zeroed coordinates
+	/// and no `child`, so they carry no meaning.
+	///
+	/// [windows]: Window
+	/// [screen]: crate::visual::Screen
+	#[must_use]
+	pub const fn output(&self) -> Option<(Coords, Option<Window>)> {
+		if self.same_screen {
+			Some((self.output_coords, self.child))
+		} else {
+			None
+		}
+	}
+}
+
 /// The [keysyms] mapped to a particular [keycode].
 ///
 /// [keysyms]: Keysym
@@ -400,12 +456,14 @@ impl Readable for GetKeyboardMapping {
 		//        and `sequence` should be context for `ReadableWithContext`.
 		//
 		// FIXME: This is a change that needs to be done for all replies...
-		buf.advance(1);
+
+		// The first byte, `1`, has already been read to know this is a reply.
 		let keysyms_per_keycode = buf.get_u8();
 		let sequence = buf.get_u16();
 
-		let length = (buf.get_u32() as usize) * 4;
-		let buf = &mut buf.take(length - HEADER);
+		// Read the length - take away the 8 bytes we've already read.
+		let length = ((buf.get_u32() as usize) * 4) + (32 - HEADER);
+		let buf = &mut buf.take(length);
 
 		// }}}
 
@@ -413,22 +471,10 @@ impl Readable for GetKeyboardMapping {
 		buf.advance(24);
 
 		let mappings = {
-			let mapping_size = usize::from(keysyms_per_keycode) * Keysym::X11_SIZE;
-			let mappings_len = buf.remaining() / mapping_size;
-
-			let mut mappings = vec![];
-
-			for _ in 0..mappings_len {
-				let mut keysyms = vec![];
-
-				for _ in 0..keysyms_per_keycode {
-					keysyms.push(Keysym::read_from(buf)?);
-				}
+			let keysyms_per_keycode = usize::from(keysyms_per_keycode);
+			let mappings_len = buf.remaining() / (keysyms_per_keycode * Keysym::X11_SIZE);
 
-				mappings.push(keysyms);
-			}
-
-			mappings
+			<Vec<KeyMapping>>::read_with(buf, &(mappings_len, keysyms_per_keycode))?
 		};
 
 		Ok(Self { sequence, mappings })
@@ -589,13 +635,28 @@ derive_xrb! {
 	}
 }
 
+impl GetKeyboardOptions {
+	/// Returns whether the key with the given [keycode] has [auto repeat mode]
+	/// enabled.
+	///
+	/// [keycode]: Keycode
+	/// [auto repeat mode]: crate::set::KeyboardOptions::auto_repeat_mode
+	#[must_use]
+	pub const fn auto_repeat(&self, keycode: Keycode) -> bool {
+		let keycode = keycode.unwrap() as usize;
+
+		// Unlike `KeyboardState`, byte `0` holds the bits for keycodes 0 to 7.
+		self.auto_repeat_modes[keycode / 8] & (1 << (keycode % 8)) != 0
+	}
+}
+
 /// Whether a [`SetButtonMapping` request] was successful.
 ///
 /// This is used in the [`SetButtonMapping` reply].
 ///
 /// [`SetButtonMapping` request]: request::SetButtonMapping
 /// [`SetButtonMapping` reply]: SetButtonMapping
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum SetButtonMappingStatus {
 	/// The [`SetButtonMapping` request] was successful.
 	///
@@ -639,6 +700,7 @@ derive_xrb! {
 		/// See [`SetButtonMappingStatus`] for more information.
 		///
 		/// [`SetButtonMapping` request]: request::SetButtonMapping
+		#[metabyte]
 		pub status: SetButtonMappingStatus,
 		[_; ..],
 	}
@@ -682,6 +744,22 @@ derive_xrb! {
 		/// [button]: Button
 		#[context(mappings_len => usize::from(*mappings_len))]
 		pub mappings: Vec<Option<Button>>,
+		[_; mappings => pad(mappings)],
+	}
+}
+
+impl SetButtonMapping {
+	/// Whether the [`SetButtonMapping` request] was unsuccessful because some
+	/// of the [mouse buttons] were held.
+	///
+	/// See [`SetButtonMappingStatus::Busy`] for more information.
+	///
+	/// [mouse buttons]: Button
+	///
+	/// [`SetButtonMapping` request]: request::SetButtonMapping
+	#[must_use]
+	pub const fn is_busy(&self) -> bool {
+		matches!(self.status, SetButtonMappingStatus::Busy)
 	}
 }
 
@@ -901,3 +979,212 @@ impl Readable for GetModifierMapping {
 		})
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_get_keyboard_mapping_round_trip() {
+		let reply = GetKeyboardMapping {
+			sequence: 4,
+			mappings: vec![
+				vec![Keysym::new(0x61), Keysym::new(0x41)],
+				vec![Keysym::new(0x62), Keysym::new(0x42)],
+				vec![Keysym::new(0x63), Keysym::new(0x43)],
+			],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		// Two keysyms per keycode, for three keycodes.
+		assert_eq!(bytes.len(), 32 + 24);
+		assert_eq!(bytes[..8], [1, 2, 0, 4, 0, 0, 0, 6]);
+	}
+
+	#[test]
+	fn test_set_button_mapping_round_trip() {
+		for (status, busy) in [
+			(SetButtonMappingStatus::Success, false),
+			(SetButtonMappingStatus::Busy, true),
+		] {
+			let reply = SetButtonMapping {
+				sequence: 5,
+				status,
+			};
+
+			assert_eq!(reply.is_busy(), busy);
+			assert_roundtrip!(reply, skip = 1);
+
+			let bytes = write(&reply);
+
+			// The status is written in the metabyte position.
+			assert_eq!(bytes.len(), 32);
+			assert_eq!(bytes[..8], [1, u8::from(busy), 0, 5, 0, 0, 0, 0]);
+		}
+	}
+
+	#[test]
+	fn test_get_button_mapping_round_trip() {
+		let reply = GetButtonMapping {
+			sequence: 6,
+			mappings: vec![Some(Button::new(3)), None, Some(Button::new(1))],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		// Three mappings padded to four bytes.
+		assert_eq!(bytes.len(), 36);
+		assert_eq!(bytes[..8], [1, 3, 0, 6, 0, 0, 0, 1]);
+		assert_eq!(bytes[32..], [3, 0, 1, 0]);
+	}
+
+	#[test]
+	fn test_get_motion_history_round_trip() {
+		let reply = GetMotionHistory {
+			sequence: 12,
+			events: vec![
+				MotionEvent {
+					time: Timestamp::new(1000),
+					coords: Coords::new(Px(10), Px(20)),
+				},
+				MotionEvent {
+					time: Timestamp::new(1016),
+					coords: Coords::new(Px(12), Px(-4)),
+				},
+			],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		// 32-byte header followed by two 8-byte entries.
+		assert_eq!(bytes.len(), 48);
+		assert_eq!(reply.length(), 4);
+		assert_eq!(bytes[8..12], [0, 0, 0, 2]);
+		assert_eq!(bytes[32..40], [0, 0, 0x03, 0xe8, 0, 10, 0, 20]);
+		assert_eq!(bytes[40..], [0, 0, 0x03, 0xf8, 0, 12, 0xff, 0xfc]);
+	}
+
+	fn keyboard_options() -> GetKeyboardOptions {
+		let mut auto_repeat_modes = [0; 32];
+		// Keycode 9 (`Escape` on most keyboards).
+		auto_repeat_modes[1] = 0b0000_0010;
+		auto_repeat_modes[31] = 0b1000_0000;
+
+		GetKeyboardOptions {
+			sequence: 2,
+			global_auto_repeat_mode: Toggle::Enabled,
+			led_mask: 0b101,
+			key_click_volume: Percentage::new(0).unwrap(),
+			bell_volume: Percentage::new(50).unwrap(),
+			bell_pitch: Hz(400),
+			bell_duration: Ms(100),
+			auto_repeat_modes,
+		}
+	}
+
+	#[test]
+	fn test_get_keyboard_options_round_trip() {
+		let reply = keyboard_options();
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 52);
+		assert_eq!(reply.length(), 5);
+		assert_eq!(bytes[..2], [1, 1]);
+		assert_eq!(bytes[20..22], [0, 0b0000_0010]);
+	}
+
+	#[test]
+	fn test_get_keyboard_options_auto_repeat() {
+		let reply = keyboard_options();
+
+		assert!(reply.auto_repeat(Keycode::new(9)));
+		assert!(reply.auto_repeat(Keycode::new(255)));
+
+		assert!(!reply.auto_repeat(Keycode::new(0)));
+		assert!(!reply.auto_repeat(Keycode::new(8)));
+		assert!(!reply.auto_repeat(Keycode::new(10)));
+		assert!(!reply.auto_repeat(Keycode::new(254)));
+	}
+
+	#[test]
+	fn test_get_cursor_options_round_trip() {
+		let reply = GetCursorOptions {
+			sequence: 9,
+			acceleration: Fraction::<Px<u16>>::new(Px(2), Px(1)).unwrap(),
+			threshold: Px(4),
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+		assert!((reply.acceleration.as_ratio() - 2.0).abs() < f32::EPSILON);
+	}
+
+	#[test]
+	fn test_query_cursor_location_round_trip() {
+		let reply = QueryCursorLocation {
+			sequence: 3,
+			same_screen: true,
+			root: Window::new(0x0000_0100),
+			child: None,
+			root_coords: Coords::new(Px(640), Px(480)),
+			target_coords: Coords::new(Px(40), Px(-80)),
+			modifiers: ModifierMask::SHIFT | ModifierMask::BUTTON_1,
+		};
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[..2], [1, 1]);
+
+		let read = QueryCursorLocation::read_from(&mut &bytes[1..]).unwrap();
+
+		assert_eq!(read, reply);
+		assert_eq!(read.cursor_location(), Coords::new(Px(640), Px(480)));
+	}
+
+	#[test]
+	fn test_convert_coordinates_round_trip() {
+		let reply = ConvertCoordinates {
+			sequence: 7,
+			same_screen: true,
+			child: Some(Window::new(0x0040_0001)),
+			output_coords: Coords::new(Px(-5), Px(12)),
+		};
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[..2], [1, 1]);
+
+		let read = ConvertCoordinates::read_from(&mut &bytes[1..]).unwrap();
+
+		assert_eq!(read, reply);
+		assert_eq!(
+			read.output(),
+			Some((Coords::new(Px(-5), Px(12)), Some(Window::new(0x0040_0001))))
+		);
+	}
+
+	#[test]
+	fn test_convert_coordinates_different_screen() {
+		let reply = ConvertCoordinates {
+			sequence: 0,
+			same_screen: false,
+			child: None,
+			output_coords: Coords::new(Px(0), Px(0)),
+		};
+
+		assert_eq!(reply.output(), None);
+	}
+}
@@ -62,8 +62,9 @@ derive_xrb! {
 		/// extension is present and it defines any [errors].
 		///
 		/// [errors]: crate::message::Error
-		/// [event code]: crate::message::Event::CODE
+		/// [error code]: crate::message::Error::CODE
 		pub first_error_code: Option<u8>,
+		[_; ..],
 	}
 
 	/// The [reply] to a [`ListExtensions` request].
@@ -185,6 +186,7 @@ derive_xrb! {
 		/// Whether access control is [enabled].
 		///
 		/// [enabled]: Toggle::Enabled
+		#[doc(alias("enabled", "mode"))]
 		#[metabyte]
 		pub access_control: Toggle,
 
@@ -202,3 +204,142 @@ derive_xrb! {
 		// added at the end here.
 	}
 }
+
+/// Information about an extension which is present on the X server.
+///
+/// This is returned by [`QueryExtension::info`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ExtensionInfo {
+	/// The [major opcode] of the extension.
+	///
+	/// [major opcode]: crate::message::Request::MAJOR_OPCODE
+	pub major_opcode: u8,
+	/// The first [event code] defined by the extension, if it defines any
+	/// [events].
+	///
+	/// [events]: crate::message::Event
+	/// [event code]: crate::message::Event::CODE
+	pub first_event_code: Option<u8>,
+	/// The first [error code] defined by the extension, if it defines any
+	/// [errors].
+	///
+	/// [errors]: crate::message::Error
+	/// [error code]: crate::message::Error::CODE
+	pub first_error_code: Option<u8>,
+}
+
+impl QueryExtension {
+	/// Returns the [`ExtensionInfo`] of the specified extension, or [`None`]
+	/// if it is not `present`.
+	#[must_use]
+	pub const fn info(&self) -> Option<ExtensionInfo> {
+		match (self.present, self.major_opcode) {
+			(true, Some(major_opcode)) => Some(ExtensionInfo {
+				major_opcode,
+				first_event_code: self.first_event_code,
+				first_error_code: self.first_error_code,
+			}),
+
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::net::{IpAddr, Ipv4Addr};
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+	use crate::String8;
+
+	#[test]
+	fn test_get_screen_saver_round_trip() {
+		let reply = GetScreenSaver {
+			sequence: 4,
+			timeout: Some(Sec(600)),
+			interval: None,
+			prefer_blanking: Toggle::Enabled,
+			allow_expose_events: Toggle::Disabled,
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[8..14], [0x02, 0x58, 0, 0, 1, 0]);
+	}
+
+	#[test]
+	fn test_query_extension_present() {
+		let reply = QueryExtension {
+			sequence: 1,
+			present: true,
+			major_opcode: Some(140),
+			first_event_code: Some(89),
+			first_error_code: None,
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[8..12], [1, 140, 89, 0]);
+
+		assert_eq!(
+			reply.info(),
+			Some(ExtensionInfo {
+				major_opcode: 140,
+				first_event_code: Some(89),
+				first_error_code: None,
+			})
+		);
+	}
+
+	#[test]
+	fn test_query_extension_absent() {
+		let reply = QueryExtension {
+			sequence: 1,
+			present: false,
+			major_opcode: None,
+			first_event_code: None,
+			first_error_code: None,
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+		assert_eq!(reply.info(), None);
+	}
+
+	#[test]
+	fn test_list_extensions_round_trip() {
+		let reply = ListExtensions {
+			sequence: 1,
+			names: vec![
+				LengthString8::from(String8::try_from("RANDR").unwrap()),
+				LengthString8::from(String8::try_from("XKEYBOARD").unwrap()),
+			],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+	}
+
+	#[test]
+	fn test_query_access_control_round_trip() {
+		let reply = QueryAccessControl {
+			sequence: 1,
+			access_control: Toggle::Enabled,
+			hosts: vec![Host::from(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))],
+		};
+
+		assert_roundtrip!(reply, skip = 1);
+
+		let bytes = write(&reply);
+
+		// 32 byte header and one 8-byte IPv4 host.
+		assert_eq!(bytes.len(), 40);
+		assert_eq!(bytes[4..8], [0, 0, 0, 2]);
+		assert_eq!(bytes[32..], [0, 0, 0, 4, 10, 0, 0, 1]);
+	}
+}
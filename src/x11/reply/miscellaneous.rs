@@ -139,7 +139,7 @@ derive_xrb! {
 		///
 		/// [window]: Window
 		///
-		/// [`Any`]: crate::Any::Any
+		/// [`Any`]: crate::Specificity::Any
 		#[doc(alias = "bytes_after")]
 		pub bytes_remaining: u32,
 
@@ -218,3 +218,28 @@ derive_xrb! {
 		[_; ..],
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_get_selection_owner_round_trip() {
+		for (owner, owner_bytes) in [
+			(None, [0, 0, 0, 0]),
+			(Some(Window::new(0x0040_0001)), [0x00, 0x40, 0x00, 0x01]),
+		] {
+			let reply = GetSelectionOwner { sequence: 9, owner };
+
+			assert_roundtrip!(reply, skip = 1);
+
+			let bytes = write(&reply);
+
+			assert_eq!(bytes.len(), 32);
+			assert_eq!(bytes[..8], [1, 0, 0, 9, 0, 0, 0, 0]);
+			assert_eq!(bytes[8..12], owner_bytes);
+		}
+	}
+}
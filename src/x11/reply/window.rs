@@ -22,6 +22,7 @@ use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
 
 use crate::{
 	message::Reply,
+	set::{Attributes, ColormapAttribute},
 	unit::Px,
 	visual::{ColorId, VisualId},
 	x11::request,
@@ -124,7 +125,7 @@ derive_xrb! {
 		///
 		/// [`Attributes::window_gravity`]: crate::set::Attributes::window_gravity
 		#[doc(alias = "win_gravity")]
-		pub window_graivty: WindowGravity,
+		pub window_gravity: WindowGravity,
 
 		/// Defines which bit planes of the [window] hold dynamic data which is
 		/// maintained for `maintain_contents` and `maintain_windows_under`.
@@ -336,3 +337,265 @@ derive_xrb! {
 		pub children: Vec<Window>,
 	}
 }
+
+impl GetWindowAttributes {
+	/// Returns the [`Attributes`] which correspond to the attributes in this
+	/// reply.
+	///
+	/// This makes it easy to read a [window]'s current attributes, change some
+	/// of them, and write them back with a [`ChangeWindowAttributes` request].
+	///
+	/// The following attributes are set:
+	/// - [`window_gravity`]
+	/// - [`override_redirect`]
+	/// - [`event_mask`], from `your_event_mask`
+	/// - [`do_not_propagate_mask`]
+	///
+	/// The following attributes are only set if the `class` is
+	/// [`WindowClass::InputOutput`], as they cause a [`Match` error] if they
+	/// are specified for an [`InputOnly`] window:
+	/// - [`maintain_contents`]
+	/// - [`bit_gravity`]
+	/// - [`maintained_planes`]
+	/// - [`maintenance_fallback_color`]
+	/// - [`maintain_windows_under`]
+	/// - [`colormap`], if `colormap` is not [`None`]
+	///
+	/// The background, border, and cursor appearance of a [window] are not
+	/// included in this reply, so they are not set.
+	///
+	/// [window]: Window
+	///
+	/// [`Attributes`]: Attributes
+	/// [`ChangeWindowAttributes` request]: request::ChangeWindowAttributes
+	/// [`InputOnly`]: WindowClass::InputOnly
+	/// [`Match` error]: crate::x11::error::Match
+	///
+	/// [`maintain_contents`]: Attributes::maintain_contents
+	/// [`bit_gravity`]: Attributes::bit_gravity
+	/// [`window_gravity`]: Attributes::window_gravity
+	/// [`maintained_planes`]: Attributes::maintained_planes
+	/// [`maintenance_fallback_color`]: Attributes::maintenance_fallback_color
+	/// [`maintain_windows_under`]: Attributes::maintain_windows_under
+	/// [`override_redirect`]: Attributes::override_redirect
+	/// [`event_mask`]: Attributes::event_mask
+	/// [`do_not_propagate_mask`]: Attributes::do_not_propagate_mask
+	/// [`colormap`]: Attributes::colormap
+	#[must_use]
+	pub fn to_attributes(&self) -> Attributes {
+		let mut builder = Attributes::builder();
+
+		builder
+			.window_gravity(self.window_gravity)
+			.override_redirect(self.override_redirect)
+			.event_mask(self.your_event_mask)
+			.do_not_propagate_mask(self.do_not_propagate_mask);
+
+		if self.class == WindowClass::InputOnly {
+			return builder.build();
+		}
+
+		builder
+			.maintain_contents(self.maintain_contents)
+			.bit_gravity(self.bit_gravity)
+			.maintained_planes(self.maintained_planes)
+			.maintenance_fallback_color(self.maintenance_fallback_color)
+			.maintain_windows_under(self.maintain_windows_under);
+
+		if let Some(colormap) = self.colormap {
+			builder.colormap(ColormapAttribute::Other(colormap));
+		}
+
+		builder.build()
+	}
+}
+
+impl QueryWindowTree {
+	/// The `target` [window]'s children, in bottom-to-top stacking order.
+	///
+	/// [window]: Window
+	#[must_use]
+	pub fn children(&self) -> &[Window] {
+		&self.children
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{test_support::write, Readable};
+
+	use super::*;
+
+	#[test]
+	fn test_get_window_attributes_without_colormap() {
+		let reply = GetWindowAttributes {
+			sequence: 7,
+			maintain_contents: MaintainContents::Never,
+			visual: VisualId::new(0x0000_0021),
+			class: WindowClass::InputOutput,
+			bit_gravity: BitGravity::Static,
+			window_gravity: WindowGravity::NorthWest,
+			maintained_planes: 0xffff_ffff,
+			maintenance_fallback_color: ColorId::new(0),
+			maintain_windows_under: false,
+			map_installed: false,
+			map_state: MapState::Viewable,
+			override_redirect: true,
+			colormap: None,
+			all_event_masks: EventMask::EXPOSURE | EventMask::KEY_PRESS,
+			your_event_mask: EventMask::EXPOSURE,
+			do_not_propagate_mask: DeviceEventMask::BUTTON_PRESS,
+		};
+
+		let bytes = write(&reply);
+
+		// 32-byte header plus 3 extra words.
+		assert_eq!(bytes.len(), 44);
+		assert_eq!(bytes[4..8], [0, 0, 0, 3]);
+		assert_eq!(bytes[12..16], [0, 1, 10, 1]);
+		assert_eq!(bytes[26], 2);
+		// `None` is written as zero.
+		assert_eq!(bytes[28..32], [0, 0, 0, 0]);
+		assert_eq!(bytes[40..44], [0, 4, 0, 0]);
+
+		let read = GetWindowAttributes::read_from(&mut &bytes[1..]).unwrap();
+
+		assert_eq!(read, reply);
+		assert_eq!(read.colormap, None);
+	}
+
+	#[test]
+	fn test_get_window_attributes_to_attributes() {
+		let reply = GetWindowAttributes {
+			sequence: 3,
+			maintain_contents: MaintainContents::Always,
+			visual: VisualId::new(0x0000_0021),
+			class: WindowClass::InputOutput,
+			bit_gravity: BitGravity::Center,
+			window_gravity: WindowGravity::SouthEast,
+			maintained_planes: 0xffff_ffff,
+			maintenance_fallback_color: ColorId::new(0),
+			maintain_windows_under: false,
+			map_installed: true,
+			map_state: MapState::Viewable,
+			override_redirect: true,
+			colormap: Some(Colormap::new(0x0000_0020)),
+			all_event_masks: EventMask::EXPOSURE | EventMask::KEY_PRESS,
+			your_event_mask: EventMask::EXPOSURE,
+			do_not_propagate_mask: DeviceEventMask::empty(),
+		};
+
+		let request = request::ChangeWindowAttributes {
+			target: Window::new(0x0040_0001),
+			attributes: reply.to_attributes(),
+		};
+
+		let bytes = write(&request);
+
+		let read = request::ChangeWindowAttributes::read_from(&mut &bytes[1..]).unwrap();
+		let attributes = read.attributes;
+
+		assert_eq!(attributes.override_redirect(), Some(&true));
+		assert_eq!(
+			attributes.maintain_contents(),
+			Some(&MaintainContents::Always),
+		);
+		assert_eq!(attributes.bit_gravity(), Some(&BitGravity::Center));
+		assert_eq!(attributes.event_mask(), Some(&EventMask::EXPOSURE));
+		assert_eq!(
+			attributes.colormap(),
+			Some(&ColormapAttribute::Other(Colormap::new(0x0000_0020))),
+		);
+		// Not included in the reply.
+		assert_eq!(attributes.background_pixmap(), None);
+		assert_eq!(attributes.cursor_appearance(), None);
+	}
+
+	#[test]
+	fn test_get_window_attributes_input_only_to_attributes() {
+		let reply = GetWindowAttributes {
+			sequence: 3,
+			maintain_contents: MaintainContents::Never,
+			visual: VisualId::new(0x0000_0021),
+			class: WindowClass::InputOnly,
+			bit_gravity: BitGravity::Forget,
+			window_gravity: WindowGravity::SouthEast,
+			maintained_planes: 0xffff_ffff,
+			maintenance_fallback_color: ColorId::new(0),
+			maintain_windows_under: false,
+			map_installed: false,
+			map_state: MapState::Viewable,
+			override_redirect: true,
+			colormap: Some(Colormap::new(0x0000_0020)),
+			all_event_masks: EventMask::KEY_PRESS,
+			your_event_mask: EventMask::KEY_PRESS,
+			do_not_propagate_mask: DeviceEventMask::empty(),
+		};
+
+		let attributes = reply.to_attributes();
+
+		assert_eq!(attributes.window_gravity(), Some(&WindowGravity::SouthEast));
+		assert_eq!(attributes.override_redirect(), Some(&true));
+		assert_eq!(attributes.event_mask(), Some(&EventMask::KEY_PRESS));
+		assert_eq!(
+			attributes.do_not_propagate_mask(),
+			Some(&DeviceEventMask::empty()),
+		);
+		// Only valid for `InputOutput` windows.
+		assert_eq!(attributes.maintain_contents(), None);
+		assert_eq!(attributes.bit_gravity(), None);
+		assert_eq!(attributes.maintained_planes(), None);
+		assert_eq!(attributes.maintenance_fallback_color(), None);
+		assert_eq!(attributes.maintain_windows_under(), None);
+		assert_eq!(attributes.colormap(), None);
+	}
+
+	#[test]
+	fn test_get_geometry_round_trip() {
+		let reply = GetGeometry {
+			sequence: 0x0102,
+			depth: 24,
+			root: Window::new(0x0304_0506),
+			geometry: Rectangle::new(Px(-10), Px(20), Px(640), Px(480)),
+			border_width: Px(2),
+		};
+
+		let bytes = write(&reply);
+
+		// 22 bytes of data followed by 10 unused bytes.
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[..8], [1, 24, 1, 2, 0, 0, 0, 0]);
+
+		// The first byte, indicating that this is a reply, has already been
+		// read when a reply is read.
+		let read = GetGeometry::read_from(&mut &bytes[1..]).unwrap();
+
+		assert_eq!(read, reply);
+		assert_eq!(read.sequence, reply.sequence);
+	}
+
+	#[test]
+	fn test_query_window_tree_round_trip() {
+		let reply = QueryWindowTree {
+			sequence: 3,
+			root: Window::new(0x0000_0100),
+			parent: None,
+			children: vec![Window::new(0x0040_0001), Window::new(0x0040_0002)],
+		};
+
+		let bytes = write(&reply);
+
+		// Header, then two children.
+		assert_eq!(bytes.len(), 32 + 8);
+		assert_eq!(bytes[4..8], [0, 0, 0, 2]);
+		assert_eq!(bytes[12..18], [0, 0, 0, 0, 0, 2]);
+
+		let read = QueryWindowTree::read_from(&mut &bytes[1..]).unwrap();
+
+		assert_eq!(read, reply);
+		assert_eq!(
+			read.children(),
+			[Window::new(0x0040_0001), Window::new(0x0040_0002)],
+		);
+	}
+}
@@ -27,3 +27,157 @@ pub mod input;
 pub mod meta;
 pub mod miscellaneous;
 pub mod window;
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{message::Request, x11::event::Expose};
+
+	macro_rules! major_opcodes {
+		($($Request:ty),+$(,)?) => {
+			[$(<$Request as Request>::MAJOR_OPCODE),+]
+		};
+	}
+
+	/// The major opcode of every request in the core protocol.
+	#[allow(deprecated, reason = "deprecated requests still have opcodes")]
+	const MAJOR_OPCODES: [u8; 120] = major_opcodes![
+		CreateWindow,
+		ChangeWindowAttributes,
+		GetWindowAttributes,
+		DestroyWindow,
+		DestroyChildren,
+		ChangeSavedWindows,
+		ReparentWindow,
+		MapWindow,
+		MapChildren,
+		UnmapWindow,
+		UnmapChildren,
+		ConfigureWindow,
+		CirculateWindow,
+		GetGeometry,
+		QueryWindowTree,
+		GetAtom,
+		GetAtomName,
+		ModifyProperty,
+		DeleteProperty,
+		GetProperty,
+		ListProperties,
+		SetSelectionOwner,
+		GetSelectionOwner,
+		ConvertSelection,
+		SendEvent<Expose>,
+		GrabCursor,
+		UngrabCursor,
+		GrabButton,
+		UngrabButton,
+		ChangeActiveCursorGrab,
+		GrabKeyboard,
+		UngrabKeyboard,
+		GrabKey,
+		UngrabKey,
+		AllowEvents,
+		GrabServer,
+		UngrabServer,
+		QueryCursorLocation,
+		GetMotionHistory,
+		ConvertCoordinates,
+		WarpCursor,
+		SetFocus,
+		GetFocus,
+		QueryKeyboard,
+		AssignFont,
+		UnassignFont,
+		QueryFont,
+		QueryTextExtents,
+		ListFonts,
+		ListFontsWithInfo,
+		SetFontSearchDirectories,
+		GetFontSearchDirectories,
+		CreatePixmap,
+		FreePixmap,
+		CreateGraphicsContext,
+		ChangeGraphicsOptions,
+		CopyGraphicsOptions,
+		SetDashes,
+		SetClipRectangles,
+		DestroyGraphicsContext,
+		ClearArea,
+		CopyArea,
+		CopyBitPlane,
+		DrawPoints,
+		DrawPath,
+		DrawLines,
+		DrawRectangles,
+		DrawArcs,
+		FillPolygon,
+		FillRectangles,
+		FillArcs,
+		PlaceImage,
+		CaptureImage,
+		DrawText8,
+		DrawText16,
+		ImageText8,
+		ImageText16,
+		CreateColormap,
+		DestroyColormap,
+		MoveColormap,
+		InstallColormap,
+		UninstallColormap,
+		ListInstalledColormaps,
+		AllocateColor,
+		AllocateNamedColor,
+		AllocateColorCells,
+		AllocateColorPlanes,
+		DestroyColormapEntries,
+		StoreColors,
+		StoreNamedColor,
+		QueryColors,
+		GetNamedColor,
+		CreateCursorAppearance,
+		CreateGlyphCursorAppearance,
+		DestroyCursorAppearance,
+		RecolorCursorAppearance,
+		QueryIdealDimensions,
+		QueryExtension,
+		ListExtensions,
+		ChangeKeyboardMapping<1>,
+		GetKeyboardMapping,
+		ChangeKeyboardOptions,
+		GetKeyboardOptions,
+		RingBell,
+		ChangeCursorOptions,
+		GetCursorOptions,
+		SetScreenSaver,
+		GetScreenSaver,
+		ChangeHosts,
+		QueryAccessControl,
+		SetAccessControl,
+		SetRetainResourcesMode,
+		KillClient,
+		RotateProperties,
+		ForceScreenSaver,
+		SetButtonMapping,
+		GetButtonMapping,
+		SetModifierMapping,
+		GetModifierMapping,
+		NoOp,
+	];
+
+	#[test]
+	fn test_major_opcodes_are_unique() {
+		let mut opcodes = MAJOR_OPCODES;
+		opcodes.sort_unstable();
+
+		let duplicates: Vec<u8> = opcodes
+			.windows(2)
+			.filter(|pair| pair[0] == pair[1])
+			.map(|pair| pair[0])
+			.collect();
+
+		assert!(
+			duplicates.is_empty(),
+			"duplicate major opcodes: {duplicates:?}"
+		);
+	}
+}
@@ -74,7 +74,8 @@ request_error! {
 /// [all entries allocated]: InitialColormapAllocation::All
 ///
 /// [colormap]: Colormap
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[doc(alias("ColormapAlloc"))]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum InitialColormapAllocation {
 	/// The [colormap] initially has no entries, or those initial entries are
 	/// defined elsewhere.
@@ -497,6 +498,26 @@ derive_xrb! {
 	}
 }
 
+impl Colormap {
+	/// Creates an [`InstallColormap` request] which installs this
+	/// `Colormap`.
+	///
+	/// [`InstallColormap` request]: InstallColormap
+	#[must_use]
+	pub const fn install(self) -> InstallColormap {
+		InstallColormap { target: self }
+	}
+
+	/// Creates an [`UninstallColormap` request] which uninstalls this
+	/// `Colormap`.
+	///
+	/// [`UninstallColormap` request]: UninstallColormap
+	#[must_use]
+	pub const fn uninstall(self) -> UninstallColormap {
+		UninstallColormap { target: self }
+	}
+}
+
 request_error! {
 	#[doc(alias("AllocNamedColor"))]
 	pub enum AllocateNamedColorError for AllocateNamedColor {
@@ -819,6 +840,7 @@ derive_xrb! {
 		/// [colormap]: Colormap
 		///
 		/// [`ColorId`s]: ColorId
+		#[doc(alias("pixels"))]
 		#[context(self::remaining => remaining / ColorId::X11_SIZE)]
 		pub colors: Vec<ColorId>,
 	}
@@ -962,6 +984,8 @@ derive_xrb! {
 		/// changed.
 		///
 		/// [colormap]: Colormap
+		#[metabyte]
+		#[doc(alias("flags"))]
 		pub mask: ColorChannelMask,
 
 		/// The [colormap] for which the [colormap] entry is changed.
@@ -1133,3 +1157,124 @@ derive_xrb! {
 		[_; name => pad(name)],
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	const COLORMAP: Colormap = Colormap::new(0x0040_0003);
+
+	#[test]
+	fn test_create_colormap_round_trip() {
+		for (initial_allocation, metabyte) in [
+			(InitialColormapAllocation::None, 0),
+			(InitialColormapAllocation::All, 1),
+		] {
+			let create = CreateColormap {
+				initial_allocation,
+				colormap_id: COLORMAP,
+				window: Window::new(0x0000_0100),
+				visual: VisualId::new(0x21),
+			};
+
+			assert_roundtrip!(create, skip = 1);
+			assert_eq!(
+				write(&create),
+				[
+					78, metabyte, 0, 4, // header
+					0x00, 0x40, 0x00, 0x03, // colormap_id
+					0x00, 0x00, 0x01, 0x00, // window
+					0x00, 0x00, 0x00, 0x21, // visual
+				],
+			);
+		}
+	}
+
+	#[test]
+	fn test_colormap_management_round_trip() {
+		assert_roundtrip!(DestroyColormap { target: COLORMAP }, skip = 1);
+		assert_roundtrip!(
+			MoveColormap {
+				colormap_id: Colormap::new(0x0040_0004),
+				source: COLORMAP,
+			},
+			skip = 1,
+		);
+
+		assert_roundtrip!(COLORMAP.install(), skip = 1);
+		assert_roundtrip!(COLORMAP.uninstall(), skip = 1);
+
+		assert_eq!(
+			write(&COLORMAP.install()),
+			[81, 0, 0, 2, 0x00, 0x40, 0x00, 0x03]
+		);
+		assert_eq!(
+			write(&COLORMAP.uninstall()),
+			[82, 0, 0, 2, 0x00, 0x40, 0x00, 0x03]
+		);
+	}
+
+	#[test]
+	fn test_list_installed_colormaps_opcode() {
+		assert_eq!(ListInstalledColormaps::MAJOR_OPCODE, 83);
+		assert_ne!(
+			ListInstalledColormaps::MAJOR_OPCODE,
+			crate::x11::request::CaptureImage::MAJOR_OPCODE,
+		);
+
+		let list = ListInstalledColormaps {
+			target: Window::new(0x0000_0100),
+		};
+
+		assert_roundtrip!(list, skip = 1);
+		assert_eq!(write(&list), [83, 0, 0, 2, 0x00, 0x00, 0x01, 0x00]);
+	}
+
+	#[test]
+	fn test_destroy_colormap_entries_round_trip() {
+		let destroy = DestroyColormapEntries {
+			target: COLORMAP,
+			plane_mask: 0x0000_0003,
+			colors: vec![ColorId::new(0x10), ColorId::new(0x20), ColorId::new(0x30)],
+		};
+
+		assert_roundtrip!(destroy, skip = 1);
+		assert_eq!(destroy.length(), 6);
+		assert_eq!(
+			write(&destroy),
+			[
+				88, 0, 0, 6, // header
+				0x00, 0x40, 0x00, 0x03, // target
+				0x00, 0x00, 0x00, 0x03, // plane_mask
+				0x00, 0x00, 0x00, 0x10, // colors
+				0x00, 0x00, 0x00, 0x20, //
+				0x00, 0x00, 0x00, 0x30, //
+			],
+		);
+	}
+
+	#[test]
+	fn test_store_named_color_round_trip() {
+		let store = StoreNamedColor {
+			mask: ColorChannelMask::RED | ColorChannelMask::GREEN | ColorChannelMask::BLUE,
+			target: COLORMAP,
+			id: ColorId::new(0x10),
+			name: String8::try_from("orange").unwrap(),
+		};
+
+		assert_roundtrip!(store, skip = 1);
+		assert_eq!(store.length(), 6);
+		assert_eq!(
+			write(&store),
+			[
+				90, 0x07, 0, 6, // header
+				0x00, 0x40, 0x00, 0x03, // target
+				0x00, 0x00, 0x00, 0x10, // id
+				0, 6, 0, 0, // name_len, unused
+				b'o', b'r', b'a', b'n', b'g', b'e', 0, 0, // name, padding
+			],
+		);
+	}
+}
@@ -319,3 +319,76 @@ derive_xrb! {
 	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable, ConstantX11Size)]
 	pub struct GetFontSearchDirectories: Request(52) -> reply::GetFontSearchDirectories;
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	fn query_text_extents_bytes(text: &str) -> Vec<u8> {
+		let request = QueryTextExtents {
+			font: Fontable::new(1),
+			text: String16::try_from(text).unwrap(),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+		assert_eq!(request.metabyte().unwrap(), bytes[1]);
+
+		bytes
+	}
+
+	#[test]
+	fn test_query_text_extents_odd_length() {
+		let bytes = query_text_extents_bytes("abc");
+
+		assert_eq!(bytes[1], 1);
+		assert_eq!(bytes.len(), 16);
+	}
+
+	#[test]
+	fn test_query_text_extents_even_length() {
+		let bytes = query_text_extents_bytes("abcd");
+
+		assert_eq!(bytes[1], 0);
+		assert_eq!(bytes.len(), 16);
+	}
+
+	#[test]
+	fn test_list_fonts_round_trip() {
+		let request = ListFonts {
+			max_names_count: 100,
+			pattern: String8::try_from("-misc-*").unwrap(),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+		assert_eq!(
+			write(&request),
+			[
+				49, 0, 0, 4, // header
+				0, 100, 0, 7, // max_names_count, pattern_len
+				b'-', b'm', b'i', b's', b'c', b'-', b'*', 0, // pattern, padding
+			],
+		);
+	}
+
+	#[test]
+	fn test_set_font_search_directories_round_trip() {
+		let request = SetFontSearchDirectories {
+			directories: vec![
+				LengthString8::from(String8::try_from("/usr/share/fonts").unwrap()),
+				LengthString8::from(String8::try_from("built-ins").unwrap()),
+			],
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		// Header, count, then 27 bytes of directories padded to 28.
+		assert_eq!(bytes.len(), 8 + 28);
+		assert_eq!(bytes[..6], [51, 0, 0, 9, 0, 2]);
+	}
+}
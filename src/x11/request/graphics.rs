@@ -32,6 +32,7 @@ use xrbk::{
 use xrbk_macro::{derive_xrb, ConstantX11Size, Readable, Writable, X11Size};
 
 use crate::{
+	connection::ConnectionSuccess,
 	message::Request,
 	unit::Px,
 	x11::{error, reply},
@@ -91,7 +92,13 @@ derive_xrb! {
 	///
 	/// If the [window] has a defined background ([`background_pixmap`] or
 	/// [`background_color`], the `area` is replaced by that background.
-	/// Otherwise, in the background is [`None`], the contents are not changed.
+	/// Otherwise, if the background is [`None`], the contents are not changed.
+	///
+	/// If the `area`'s `width` is zero, it is replaced with the width of the
+	/// [window] minus the `area`'s `x` coordinate. Likewise, if the `area`'s
+	/// `height` is zero, it is replaced with the height of the [window] minus
+	/// the `area`'s `y` coordinate. An `area` of all zeros therefore clears the
+	/// whole [window]; see [`Window::clear_all`].
 	///
 	/// # Errors
 	/// A [`Window` error] is generated if `target` does not refer to a defined
@@ -141,11 +148,56 @@ derive_xrb! {
 		/// The `x` and `y` coordinates are relative to the top-left corner of
 		/// the `target` [window].
 		///
+		/// A `width` or `height` of zero extends the `area` to the right or
+		/// bottom edge of the `target` [window] respectively.
+		///
 		/// [window]: Window
 		pub area: Rectangle,
 	}
 }
 
+impl Window {
+	/// Creates a [`ClearArea` request] which clears the given `area` of this
+	/// `Window`.
+	///
+	/// A `width` or `height` of zero in the `area` extends it to the right or
+	/// bottom edge of this `Window` respectively.
+	///
+	/// [`ClearArea` request]: ClearArea
+	#[must_use]
+	pub const fn clear_area(self, area: Rectangle, graphics_exposure: bool) -> ClearArea {
+		ClearArea {
+			graphics_exposure,
+			target: self,
+			area,
+		}
+	}
+
+	/// Creates a [`ClearArea` request] which clears the whole of this
+	/// `Window`.
+	///
+	/// The `area` of the [request] is all zeros, which the X server interprets
+	/// as extending to the edges of this `Window`. No
+	/// [`GraphicsExposure` events] are generated.
+	///
+	/// [`ClearArea` request]: ClearArea
+	/// [request]: Request
+	///
+	/// [`GraphicsExposure` events]: crate::x11::event::GraphicsExposure
+	#[must_use]
+	pub const fn clear_all(self) -> ClearArea {
+		self.clear_area(
+			Rectangle {
+				x: Px(0),
+				y: Px(0),
+				width: Px(0),
+				height: Px(0),
+			},
+			false,
+		)
+	}
+}
+
 request_error! {
 	pub enum CopyAreaError for CopyArea {
 		Drawable,
@@ -278,7 +330,6 @@ derive_xrb! {
 }
 
 request_error! {
-	#[doc(alias("CopyPlaneError"))]
 	pub enum CopyBitPlaneError for CopyBitPlane {
 		Drawable,
 		GraphicsContext,
@@ -346,7 +397,6 @@ derive_xrb! {
 	/// [`GraphicsContext` error]: error::GraphicsContext
 	/// [`Match` error]: error::Match
 	/// [`Value` error]: error::Value
-	#[doc(alias("CopyPlane"))]
 	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable, ConstantX11Size)]
 	pub struct CopyBitPlane: Request(63, CopyBitPlaneError) {
 		/// The [drawable] used as the source in this graphics operation.
@@ -442,6 +492,18 @@ derive_xrb! {
 	}
 }
 
+/// The name of the [`CopyBitPlane` request] in the X11 protocol.
+///
+/// [`CopyBitPlane`] is the canonical name in XRB; this alias is provided so
+/// that code written with the X11 protocol's name continues to work.
+///
+/// [`CopyBitPlane` request]: CopyBitPlane
+pub type CopyPlane = CopyBitPlane;
+/// The name of the [`CopyBitPlaneError`] in the X11 protocol.
+///
+/// [`CopyBitPlaneError`] is the canonical name in XRB.
+pub type CopyPlaneError = CopyBitPlaneError;
+
 request_error! {
 	#[doc(alias("PolyPointError", "DrawPointError"))]
 	pub enum DrawPointsError for DrawPoints {
@@ -459,12 +521,15 @@ request_error! {
 ///
 /// [coordinates]: Coords
 /// [drawable]: Drawable
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum CoordinateMode {
 	/// [Coordinates] are relative to the top-left corner of the [drawable].
 	///
+	/// This is called `Origin` in the X11 protocol.
+	///
 	/// [Coordinates]: Coords
 	/// [drawable]: Drawable
+	#[doc(alias("Origin"))]
 	Drawable,
 
 	/// [Coordinates][coords] are relative to the [coordinates][coords] of the
@@ -706,6 +771,81 @@ derive_xrb! {
 	}
 }
 
+impl DrawPath {
+	/// Creates a new `DrawPath` request which draws a closed path through the
+	/// given `points`.
+	///
+	/// If the path is not already [closed], a point is added to the end of
+	/// `points` in the same location as the first point.
+	///
+	/// [closed]: DrawPath::is_closed
+	#[must_use]
+	pub fn closed(
+		target: Drawable, graphics_context: GraphicsContext, coordinate_mode: CoordinateMode,
+		points: Vec<Coords>,
+	) -> Self {
+		let mut request = Self {
+			coordinate_mode,
+			target,
+			graphics_context,
+			points,
+		};
+
+		if !request.is_closed() && !request.points.is_empty() {
+			let closing_point = match request.coordinate_mode {
+				CoordinateMode::Drawable => request.points[0],
+
+				// The offset back to the first point is the inverse of the sum
+				// of every other offset.
+				CoordinateMode::Previous => {
+					let (x, y) = request.offset_from_first();
+
+					Coords::new(Px(x.wrapping_neg()), Px(y.wrapping_neg()))
+				},
+			};
+
+			request.points.push(closing_point);
+		}
+
+		request
+	}
+
+	/// Whether the last point in this path is in the same location as the
+	/// first point, which means the path is closed with no endpoints.
+	///
+	/// A path with fewer than two points is never closed.
+	///
+	/// If the `coordinate_mode` is [`CoordinateMode::Previous`], the location
+	/// of the last point is found by adding each point's [coordinates] to the
+	/// previous point's location.
+	///
+	/// [coordinates]: Coords
+	#[must_use]
+	pub fn is_closed(&self) -> bool {
+		if self.points.len() < 2 {
+			return false;
+		}
+
+		match self.coordinate_mode {
+			CoordinateMode::Drawable => self.points.first() == self.points.last(),
+			CoordinateMode::Previous => self.offset_from_first() == (0, 0),
+		}
+	}
+
+	/// Returns the offset of the last point from the first point when the
+	/// `coordinate_mode` is [`CoordinateMode::Previous`].
+	fn offset_from_first(&self) -> (i16, i16) {
+		self.points.iter().skip(1).fold(
+			(0, 0),
+			|(x, y): (i16, i16),
+			 Coords {
+			     x: Px(dx),
+			     y: Px(dy),
+			 }| { (x.wrapping_add(*dx), y.wrapping_add(*dy)) },
+		)
+	}
+}
+
 request_error! {
 	#[doc(alias("PolySegmentError", "DrawSegmentError"))]
 	pub enum DrawLinesError for DrawLines {
@@ -715,9 +855,60 @@ request_error! {
 	}
 }
 
+/// A list of points, each represented by its [coordinates].
+///
+/// `Points` can be collected from an iterator of [`Coords`] or of `(x, y)`
+/// pixel coordinates, then converted into the `points` of a
+/// [`DrawPoints` request], [`DrawPath` request], or [`FillPolygon` request]
+/// with [`Into`].
+///
+/// [coordinates]: Coords
+///
+/// [`DrawPoints` request]: DrawPoints
+/// [`DrawPath` request]: DrawPath
+/// [`FillPolygon` request]: FillPolygon
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Points(Vec<Coords>);
+
+impl Points {
+	/// Returns the [coordinates] of each point in this list.
+	///
+	/// [coordinates]: Coords
+	#[must_use]
+	pub fn as_slice(&self) -> &[Coords] {
+		&self.0
+	}
+}
+
+impl From<Vec<Coords>> for Points {
+	fn from(points: Vec<Coords>) -> Self {
+		Self(points)
+	}
+}
+
+impl From<Points> for Vec<Coords> {
+	fn from(Points(points): Points) -> Self {
+		points
+	}
+}
+
+impl FromIterator<Coords> for Points {
+	fn from_iter<I: IntoIterator<Item = Coords>>(iter: I) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+impl FromIterator<(i16, i16)> for Points {
+	fn from_iter<I: IntoIterator<Item = (i16, i16)>>(iter: I) -> Self {
+		iter.into_iter()
+			.map(|(x, y)| Coords::new(Px(x), Px(y)))
+			.collect()
+	}
+}
+
 /// A line from the given `start` point to the given `end` point.
 #[doc(alias("Segment"))]
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable, ConstantX11Size)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable, ConstantX11Size)]
 pub struct Line {
 	/// The start of the line.
 	pub start: Coords,
@@ -725,6 +916,34 @@ pub struct Line {
 	pub end: Coords,
 }
 
+impl Line {
+	/// Creates a new `Line` from the given `start` point to the given `end`
+	/// point.
+	#[must_use]
+	pub const fn new(start: Coords, end: Coords) -> Self {
+		Self { start, end }
+	}
+
+	/// Creates a new `Line` from the given `start` point to the given `end`
+	/// point, each given as `(x, y)` pixel coordinates.
+	#[must_use]
+	pub const fn from_points((start_x, start_y): (i16, i16), (end_x, end_y): (i16, i16)) -> Self {
+		Self::new(
+			Coords::new(Px(start_x), Px(start_y)),
+			Coords::new(Px(end_x), Px(end_y)),
+		)
+	}
+
+	/// Returns the length of the line, measured in pixels.
+	#[must_use]
+	pub fn length(&self) -> f64 {
+		let width = f64::from(self.end.x.0) - f64::from(self.start.x.0);
+		let height = f64::from(self.end.y.0) - f64::from(self.start.y.0);
+
+		width.hypot(height)
+	}
+}
+
 derive_xrb! {
 	/// A [request] that draws the given `lines`.
 	///
@@ -1064,6 +1283,18 @@ derive_xrb! {
 	}
 }
 
+impl DrawArcs {
+	/// Creates a new `DrawArcs` request which draws a single `arc`.
+	#[must_use]
+	pub fn single(target: Drawable, graphics_context: GraphicsContext, arc: Arc) -> Self {
+		Self {
+			target,
+			graphics_context,
+			arcs: vec![arc],
+		}
+	}
+}
+
 request_error! {
 	#[doc(alias("FillPolyError"))]
 	pub enum FillPolygonError for FillPolygon {
@@ -1080,6 +1311,7 @@ request_error! {
 /// This is used in the [`FillPolygon` request].
 ///
 /// [`FillPolygon` request]: FillPolygon
+#[doc(alias("Shape"))]
 #[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum ShapeMode {
 	/// The shape may intersect itself.
@@ -1325,6 +1557,110 @@ derive_xrb! {
 	}
 }
 
+/// A `target` [drawable] and [`GraphicsContext`] shared by a series of drawing
+/// [requests].
+///
+/// Each method builds the corresponding [request] with this session's
+/// `target` and `graphics_context`, so that they need not be repeated for
+/// every [request].
+///
+/// [drawable]: Drawable
+/// [request]: crate::message::Request
+/// [requests]: crate::message::Request
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DrawSession {
+	/// The [drawable] on which the [requests] draw.
+	///
+	/// [drawable]: Drawable
+	/// [requests]: crate::message::Request
+	pub target: Drawable,
+	/// The [`GraphicsContext`] used in the [requests].
+	///
+	/// [requests]: crate::message::Request
+	pub graphics_context: GraphicsContext,
+}
+
+impl DrawSession {
+	/// Creates a new `DrawSession` drawing on the given `target` with the
+	/// given `graphics_context`.
+	#[must_use]
+	pub const fn new(target: Drawable, graphics_context: GraphicsContext) -> Self {
+		Self {
+			target,
+			graphics_context,
+		}
+	}
+
+	/// Creates a [`DrawPoints` request] which draws the given `points`.
+	///
+	/// [`DrawPoints` request]: DrawPoints
+	#[must_use]
+	pub fn points(
+		&self, coordinate_mode: CoordinateMode, points: impl Into<Vec<Coords>>,
+	) -> DrawPoints {
+		DrawPoints {
+			coordinate_mode,
+			target: self.target,
+			graphics_context: self.graphics_context,
+			points: points.into(),
+		}
+	}
+
+	/// Creates a [`DrawPath` request] which draws a path through the given
+	/// `points`.
+	///
+	/// [`DrawPath` request]: DrawPath
+	#[must_use]
+	pub fn path(
+		&self, coordinate_mode: CoordinateMode, points: impl Into<Vec<Coords>>,
+	) -> DrawPath {
+		DrawPath {
+			coordinate_mode,
+			target: self.target,
+			graphics_context: self.graphics_context,
+			points: points.into(),
+		}
+	}
+
+	/// Creates a [`DrawLines` request] which draws the given `lines`.
+	///
+	/// [`DrawLines` request]: DrawLines
+	#[must_use]
+	pub const fn lines(&self, lines: Vec<Line>) -> DrawLines {
+		DrawLines {
+			target: self.target,
+			graphics_context: self.graphics_context,
+			lines,
+		}
+	}
+
+	/// Creates a [`DrawRectangles` request] which draws the outlines of the
+	/// given `rectangles`.
+	///
+	/// [`DrawRectangles` request]: DrawRectangles
+	#[must_use]
+	pub const fn rectangles(&self, rectangles: Vec<Rectangle>) -> DrawRectangles {
+		DrawRectangles {
+			target: self.target,
+			graphics_context: self.graphics_context,
+			rectangles,
+		}
+	}
+
+	/// Creates a [`FillRectangles` request] which fills the given
+	/// `rectangles`.
+	///
+	/// [`FillRectangles` request]: FillRectangles
+	#[must_use]
+	pub const fn fill_rectangles(&self, rectangles: Vec<Rectangle>) -> FillRectangles {
+		FillRectangles {
+			target: self.target,
+			graphics_context: self.graphics_context,
+			rectangles,
+		}
+	}
+}
+
 request_error! {
 	#[doc(alias("PolyFillArcError"))]
 	pub enum FillArcsError for FillArcs {
@@ -1438,6 +1774,18 @@ derive_xrb! {
 	}
 }
 
+impl FillArcs {
+	/// Creates a new `FillArcs` request which fills a single `arc`.
+	#[must_use]
+	pub fn single(target: Drawable, graphics_context: GraphicsContext, arc: Arc) -> Self {
+		Self {
+			target,
+			graphics_context,
+			arcs: vec![arc],
+		}
+	}
+}
+
 request_error! {
 	#[doc(alias("PutImageError"))]
 	pub enum PlaceImageError for PlaceImage {
@@ -1451,8 +1799,8 @@ request_error! {
 /// The format of an image sent in a [`PlaceImage` request].
 ///
 /// [`PlaceImage` request]: PlaceImage
-#[doc(alias("PutImageFormat"))]
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[doc(alias("PutImageFormat", "BitmapFormat"))]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum PlaceImageFormat {
 	/// The image must be in XY format.
 	///
@@ -1625,6 +1973,130 @@ derive_xrb! {
 	}
 }
 
+/// An error returned when the image data given for a [`PlaceImage` request]
+/// does not match its `format`, `dimensions`, and `depth`.
+///
+/// [`PlaceImage` request]: PlaceImage
+#[derive(Debug, Hash, PartialEq, Eq, Error)]
+pub enum PlaceImageDataError {
+	/// There is no [pixmap format] for the given `depth`.
+	///
+	/// [pixmap format]: crate::visual::Format
+	#[error("no pixmap format is defined for a depth of {0}")]
+	UnsupportedDepth(u8),
+
+	/// The `width` of the image plus its `left_padding` does not fit in a
+	/// [`u16`].
+	#[error("an image width of {width} plus a left padding of {left_padding} is too wide")]
+	TooWide {
+		/// The width of the image.
+		width: u16,
+		/// The left padding of the image.
+		left_padding: u8,
+	},
+
+	/// The scanline padding given in the `connection` setup for the image's
+	/// format is zero.
+	#[error("the scanline padding for the image's format is zero")]
+	ZeroScanlinePad,
+
+	/// The length of the image data was not the length required.
+	#[error("expected {expected} bytes of scanline-padded image data, found {found}")]
+	WrongLength {
+		/// The length of image data required.
+		expected: usize,
+		/// The length of the image data that was given.
+		found: usize,
+	},
+}
+
+impl PlaceImage {
+	/// Creates a new `PlaceImage` request, verifying that `data` is the
+	/// correct length for the given `format`, `dimensions`, and `depth`.
+	///
+	/// Each scanline of the image must be padded according to the image and
+	/// bitmap formats given in the `connection` setup:
+	/// - For [`PlaceImageFormat::Bitmap`] and [`PlaceImageFormat::XyPixmap`],
+	///   each scanline of each bit plane (including the `left_padding`) is
+	///   padded to a multiple of `bitmap_format_scanline_padding` bits. There
+	///   are `depth` bit planes.
+	/// - For [`PlaceImageFormat::Zpixmap`], each scanline is padded to a
+	///   multiple of the `scanline_pad` of the [pixmap format] for `depth`.
+	///
+	/// # Errors
+	/// A [`PlaceImageDataError::UnsupportedDepth`] error is returned if
+	/// `format` is [`PlaceImageFormat::Zpixmap`] and there is no
+	/// [pixmap format] for the given `depth`.
+	///
+	/// A [`PlaceImageDataError::TooWide`] error is returned if `format` is
+	/// [`PlaceImageFormat::Bitmap`] or [`PlaceImageFormat::XyPixmap`] and the
+	/// `width` of `dimensions` plus `left_padding` does not fit in a [`u16`].
+	///
+	/// A [`PlaceImageDataError::ZeroScanlinePad`] error is returned if the
+	/// scanline padding given in the `connection` setup for `format` is zero.
+	///
+	/// A [`PlaceImageDataError::WrongLength`] error is returned if the length
+	/// of `data` is not the required length.
+	///
+	/// [pixmap format]: crate::visual::Format
+	#[allow(
+		clippy::too_many_arguments,
+		reason = "These are the fields of `PlaceImage`, plus the `connection` setup."
+	)]
+	pub fn new_checked(
+		format: PlaceImageFormat, target: Drawable, graphics_context: GraphicsContext,
+		dimensions: Dimensions, coordinates: Coords, left_padding: u8, depth: u8, data: Vec<u8>,
+		connection: &ConnectionSuccess,
+	) -> Result<Self, PlaceImageDataError> {
+		let Dimensions {
+			width: Px(width),
+			height: Px(height),
+		} = dimensions;
+
+		let scanline_len = match format {
+			PlaceImageFormat::Bitmap | PlaceImageFormat::XyPixmap => {
+				let padded_width =
+					width
+						.checked_add(left_padding.into())
+						.ok_or(PlaceImageDataError::TooWide {
+							width,
+							left_padding,
+						})?;
+
+				connection
+					.bitmap_scanline_len(padded_width)
+					.ok_or(PlaceImageDataError::ZeroScanlinePad)?
+					* usize::from(depth)
+			},
+
+			PlaceImageFormat::Zpixmap => connection
+				.pixmap_format(depth)
+				.ok_or(PlaceImageDataError::UnsupportedDepth(depth))?
+				.zpixmap_scanline_len(width)
+				.ok_or(PlaceImageDataError::ZeroScanlinePad)?,
+		};
+		let expected = scanline_len * usize::from(height);
+
+		if data.len() == expected {
+			Ok(Self {
+				format,
+				target,
+				graphics_context,
+				dimensions,
+				coordinates,
+				left_padding,
+				depth,
+				data,
+			})
+		} else {
+			Err(PlaceImageDataError::WrongLength {
+				expected,
+				found: data.len(),
+			})
+		}
+	}
+}
+
 request_error! {
 	#[doc(alias("GetImageError"))]
 	pub enum CaptureImageError for CaptureImage {
@@ -1638,13 +2110,20 @@ request_error! {
 ///
 /// This is used in the [`CaptureImage` request].
 ///
+/// The values of this enum are shared with [`PlaceImageFormat`], but
+/// [`PlaceImageFormat::Bitmap`] (`0`) is not a valid format for capturing an
+/// image: [`XyPixmap`] is encoded as `1` and [`Zpixmap`] as `2`.
+///
+/// [`XyPixmap`]: CaptureImageFormat::XyPixmap
+/// [`Zpixmap`]: CaptureImageFormat::Zpixmap
+///
 /// [`CaptureImage` request]: CaptureImage
 /// [`CaptureImage` reply]: reply::CaptureImage
 #[doc(alias("GetImageFormat"))]
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum CaptureImageFormat {
 	/// The image is returned in XY format.
-	XyPixmap,
+	XyPixmap = 1,
 
 	/// The image is returned in Z format.
 	Zpixmap,
@@ -2395,7 +2874,7 @@ pub struct DrawText16 {
 }
 
 impl Request for DrawText16 {
-	type OtherErrors = DrawText8Error;
+	type OtherErrors = DrawText16Error;
 	type Reply = ();
 
 	const MAJOR_OPCODE: u8 = 75;
@@ -2616,6 +3095,7 @@ derive_xrb! {
 		///
 		/// [rectangle]: Rectangle
 		/// [request docs]: ImageText8
+		#[doc(alias("coords", "x", "y"))]
 		pub coordinates: Coords,
 
 		/// The text which is to be drawn.
@@ -2753,6 +3233,7 @@ derive_xrb! {
 		///
 		/// [rectangle]: Rectangle
 		/// [request docs]: ImageText16
+		#[doc(alias("coords", "x", "y"))]
 		pub coordinates: Coords,
 
 		/// The text which is to be drawn.
@@ -2761,3 +3242,513 @@ derive_xrb! {
 		[_; string => pad(string)],
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+	use crate::{connection::ImageEndianness, unit::Angle};
+
+	fn place_image(
+		format: PlaceImageFormat, width: u16, height: u16, depth: u8, data_len: usize,
+	) -> Result<PlaceImage, PlaceImageDataError> {
+		PlaceImage::new_checked(
+			format,
+			Drawable::new(1),
+			GraphicsContext::new(2),
+			Dimensions::new(Px(width), Px(height)),
+			Coords::new(Px(0), Px(0)),
+			0,
+			depth,
+			vec![0; data_len],
+			&ConnectionSuccess::test_setup(ImageEndianness::LittleEndian),
+		)
+	}
+
+	fn arc(x: i16) -> Arc {
+		Arc::new(
+			Rectangle::new(Px(x), Px(0), Px(10), Px(10)),
+			Angle(0),
+			Angle::from_degrees(360.0),
+		)
+	}
+
+	#[test]
+	fn test_draw_arcs_round_trip() {
+		assert_roundtrip!(
+			DrawArcs {
+				target: Drawable::new(1),
+				graphics_context: GraphicsContext::new(2),
+				arcs: vec![],
+			},
+			skip = 1,
+		);
+
+		assert_roundtrip!(
+			DrawArcs {
+				target: Drawable::new(1),
+				graphics_context: GraphicsContext::new(2),
+				arcs: vec![arc(0), arc(20)],
+			},
+			skip = 1,
+		);
+
+		assert_roundtrip!(
+			DrawArcs::single(Drawable::new(1), GraphicsContext::new(2), arc(0)),
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_fill_arcs_round_trip() {
+		assert_roundtrip!(
+			FillArcs {
+				target: Drawable::new(1),
+				graphics_context: GraphicsContext::new(2),
+				arcs: vec![],
+			},
+			skip = 1,
+		);
+
+		assert_roundtrip!(
+			FillArcs {
+				target: Drawable::new(1),
+				graphics_context: GraphicsContext::new(2),
+				arcs: vec![arc(0), arc(20)],
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_fill_polygon_convex() {
+		let request = FillPolygon {
+			target: Drawable::new(1),
+			graphics_context: GraphicsContext::new(2),
+			shape: ShapeMode::Convex,
+			coordinate_mode: CoordinateMode::Drawable,
+			points: vec![
+				Coords::new(Px(0), Px(0)),
+				Coords::new(Px(10), Px(0)),
+				Coords::new(Px(0), Px(10)),
+			],
+		};
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 28);
+		// Length, in 4-byte units.
+		assert_eq!(bytes[2..4], [0, 7]);
+		// `shape` and `coordinate_mode`.
+		assert_eq!(bytes[12..14], [2, 0]);
+		// The second point.
+		assert_eq!(bytes[20..24], [0, 10, 0, 0]);
+
+		assert_roundtrip!(request, skip = 1);
+	}
+
+	#[test]
+	fn test_copy_plane_alias() {
+		let request = CopyPlane {
+			source: Drawable::new(1),
+			destination: Drawable::new(2),
+			graphics_context: GraphicsContext::new(3),
+			source_coords: Coords::new(Px(0), Px(0)),
+			destination_coords: Coords::new(Px(10), Px(20)),
+			dimensions: Dimensions::new(Px(30), Px(40)),
+			bit_plane: 1 << 3,
+		};
+
+		assert_eq!(CopyPlane::MAJOR_OPCODE, CopyBitPlane::MAJOR_OPCODE);
+		assert_eq!(CopyPlane::MAJOR_OPCODE, 63);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), CopyPlane::X11_SIZE);
+		assert_eq!(bytes[..4], [63, 0, 0, 8]);
+		assert_eq!(bytes[28..], [0, 0, 0, 8]);
+	}
+
+	#[test]
+	fn test_draw_path_is_closed() {
+		let path = |coordinate_mode, points: &[(i16, i16)]| DrawPath {
+			coordinate_mode,
+			target: Drawable::new(1),
+			graphics_context: GraphicsContext::new(2),
+			points: points.iter().copied().collect::<Points>().into(),
+		};
+
+		assert!(path(
+			CoordinateMode::Drawable,
+			&[(0, 0), (10, 0), (0, 10), (0, 0)]
+		)
+		.is_closed());
+		assert!(!path(CoordinateMode::Drawable, &[(0, 0), (10, 0), (0, 10)]).is_closed());
+		assert!(!path(CoordinateMode::Drawable, &[(5, 5)]).is_closed());
+		assert!(!path(CoordinateMode::Drawable, &[]).is_closed());
+
+		// Each point is relative to the previous point.
+		assert!(path(
+			CoordinateMode::Previous,
+			&[(5, 5), (10, 0), (-10, 10), (0, -10)]
+		)
+		.is_closed());
+		assert!(!path(
+			CoordinateMode::Previous,
+			&[(5, 5), (10, 0), (-10, 10), (5, 5)]
+		)
+		.is_closed());
+	}
+
+	#[test]
+	fn test_draw_path_closed() {
+		let points = |points: &[(i16, i16)]| -> Vec<Coords> {
+			points.iter().copied().collect::<Points>().into()
+		};
+		let closed = |coordinate_mode, path: &[(i16, i16)]| {
+			DrawPath::closed(
+				Drawable::new(1),
+				GraphicsContext::new(2),
+				coordinate_mode,
+				points(path),
+			)
+		};
+
+		let request = closed(CoordinateMode::Drawable, &[(0, 0), (10, 0), (0, 10)]);
+		assert!(request.is_closed());
+		assert_eq!(request.points, points(&[(0, 0), (10, 0), (0, 10), (0, 0)]));
+
+		// An already closed path is left unchanged.
+		let request = closed(CoordinateMode::Drawable, &[(0, 0), (10, 0), (0, 0)]);
+		assert_eq!(request.points, points(&[(0, 0), (10, 0), (0, 0)]));
+
+		let request = closed(CoordinateMode::Previous, &[(5, 5), (10, 0), (-10, 10)]);
+		assert!(request.is_closed());
+		assert_eq!(
+			request.points,
+			points(&[(5, 5), (10, 0), (-10, 10), (0, -10)])
+		);
+
+		assert!(closed(CoordinateMode::Drawable, &[]).points.is_empty());
+		assert_roundtrip!(request, skip = 1);
+	}
+
+	#[test]
+	fn test_request_error_types() {
+		fn assert_other_errors<R: Request<OtherErrors = E>, E>() {}
+
+		assert_other_errors::<ClearArea, ClearAreaError>();
+		assert_other_errors::<DrawText8, DrawText8Error>();
+		assert_other_errors::<DrawText16, DrawText16Error>();
+	}
+
+	#[test]
+	fn test_coordinate_mode_encoding() {
+		// `Drawable` is the protocol's `Origin` mode.
+		for (mode, encoded) in [(CoordinateMode::Drawable, 0), (CoordinateMode::Previous, 1)] {
+			let bytes = write(&mode);
+
+			assert_eq!(bytes, [encoded]);
+			assert_eq!(CoordinateMode::read_from(&mut &bytes[..]).unwrap(), mode);
+		}
+	}
+
+	#[test]
+	fn test_draw_session() {
+		let session = DrawSession::new(Drawable::new(1), GraphicsContext::new(2));
+		let rectangles = vec![
+			Rectangle::new(Px(0), Px(0), Px(10), Px(10)),
+			Rectangle::new(Px(5), Px(5), Px(20), Px(15)),
+		];
+
+		assert_eq!(
+			session.rectangles(rectangles.clone()),
+			DrawRectangles {
+				target: Drawable::new(1),
+				graphics_context: GraphicsContext::new(2),
+				rectangles: rectangles.clone(),
+			},
+		);
+		assert_eq!(
+			session.fill_rectangles(rectangles.clone()).rectangles,
+			rectangles,
+		);
+
+		let path = session.path(
+			CoordinateMode::Drawable,
+			[(0, 0), (10, 0)].into_iter().collect::<Points>(),
+		);
+		assert_eq!(path.target, session.target);
+		assert_eq!(path.graphics_context, session.graphics_context);
+		assert_roundtrip!(path, skip = 1);
+	}
+
+	#[test]
+	fn test_points_from_range() {
+		let points: Points = (0..4).map(|i| (i, i * 2)).collect();
+
+		assert_eq!(
+			points.as_slice(),
+			[
+				Coords::new(Px(0), Px(0)),
+				Coords::new(Px(1), Px(2)),
+				Coords::new(Px(2), Px(4)),
+				Coords::new(Px(3), Px(6)),
+			],
+		);
+		assert_eq!(
+			points,
+			(0..4)
+				.map(|i| Coords::new(Px(i), Px(i * 2)))
+				.collect::<Points>(),
+		);
+
+		let request = DrawPoints {
+			coordinate_mode: CoordinateMode::Drawable,
+			target: Drawable::new(1),
+			graphics_context: GraphicsContext::new(2),
+			points: points.into(),
+		};
+
+		assert_eq!(request.points.len(), 4);
+		assert_roundtrip!(request, skip = 1);
+	}
+
+	#[test]
+	fn test_line_construction() {
+		let line = Line::from_points((1, 2), (-3, 4));
+
+		assert_eq!(
+			line,
+			Line::new(Coords::new(Px(1), Px(2)), Coords::new(Px(-3), Px(4))),
+		);
+		assert_eq!(line.start, Coords::new(Px(1), Px(2)));
+		assert_eq!(line.end, Coords::new(Px(-3), Px(4)));
+	}
+
+	#[test]
+	fn test_line_length() {
+		assert!((Line::from_points((0, 0), (3, 4)).length() - 5.0).abs() < f64::EPSILON);
+		assert!((Line::from_points((3, 4), (0, 0)).length() - 5.0).abs() < f64::EPSILON);
+		assert!(Line::from_points((7, 7), (7, 7)).length().abs() < f64::EPSILON);
+
+		// The length does not overflow at the extremes of `i16`.
+		let line = Line::from_points((i16::MIN, 0), (i16::MAX, 0));
+		assert!((line.length() - 65535.0).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	fn test_window_clear_area() {
+		let window = Window::new(0x0040_0001);
+		let area = Rectangle::new(Px(10), Px(20), Px(30), Px(40));
+		let request = window.clear_area(area, true);
+
+		assert_eq!(request.target, window);
+		assert_eq!(request.area, area);
+		assert!(request.graphics_exposure);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(
+			bytes,
+			[
+				61, 1, 0, 4, // header
+				0x00, 0x40, 0x00, 0x01, // target
+				0, 10, 0, 20, 0, 30, 0, 40, // area
+			],
+		);
+	}
+
+	#[test]
+	fn test_window_clear_all() {
+		let window = Window::new(0x0040_0001);
+		let request = window.clear_all();
+
+		assert_eq!(request.target, window);
+		assert!(!request.graphics_exposure);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes[..4], [61, 0, 0, 4]);
+		// A zero `area` clears to the edges of the window.
+		assert_eq!(bytes[8..], [0; 8]);
+	}
+
+	#[test]
+	fn test_image_text_8_round_trip() {
+		let request = ImageText8 {
+			target: Drawable::new(1),
+			graphics_context: GraphicsContext::new(2),
+			coordinates: Coords::new(Px(5), Px(15)),
+			string: String8::try_from("hello").unwrap(),
+		};
+
+		assert_eq!(ImageText8::MAJOR_OPCODE, 76);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(
+			bytes,
+			[
+				76, 5, 0, 6, // header
+				0, 0, 0, 1, // target
+				0, 0, 0, 2, // graphics_context
+				0, 5, 0, 15, // coordinates
+				b'h', b'e', b'l', b'l', b'o', 0, 0, 0, // string
+			],
+		);
+	}
+
+	#[test]
+	fn test_image_text_16_round_trip() {
+		let request = ImageText16 {
+			target: Drawable::new(1),
+			graphics_context: GraphicsContext::new(2),
+			coordinates: Coords::new(Px(5), Px(15)),
+			string: String16::try_from("hé!").unwrap(),
+		};
+
+		assert_eq!(ImageText16::MAJOR_OPCODE, 77);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(
+			bytes,
+			[
+				77, 3, 0, 6, // header
+				0, 0, 0, 1, // target
+				0, 0, 0, 2, // graphics_context
+				0, 5, 0, 15, // coordinates
+				0, b'h', 0, 0xe9, 0, b'!', 0, 0, // string
+			],
+		);
+	}
+
+	#[test]
+	fn test_place_image_zpixmap_round_trip() {
+		// 2x2 pixels at a depth of 24 use 4 bytes per pixel.
+		let request = PlaceImage::new_checked(
+			PlaceImageFormat::Zpixmap,
+			Drawable::new(1),
+			GraphicsContext::new(2),
+			Dimensions::new(Px(2), Px(2)),
+			Coords::new(Px(10), Px(20)),
+			0,
+			24,
+			(0..16).collect(),
+			&ConnectionSuccess::test_setup(ImageEndianness::LittleEndian),
+		)
+		.unwrap();
+
+		assert_eq!(PlaceImage::MAJOR_OPCODE, 72);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 24 + 16);
+		assert_eq!(
+			bytes[..24],
+			[
+				72, 2, 0, 10, // header
+				0, 0, 0, 1, // target
+				0, 0, 0, 2, // graphics_context
+				0, 2, 0, 2, // dimensions
+				0, 10, 0, 20, // coordinates
+				0, 24, 0, 0, // left_padding, depth
+			],
+		);
+		assert_eq!(bytes[24..], (0..16).collect::<Vec<u8>>());
+	}
+
+	#[test]
+	fn test_capture_image_zpixmap_round_trip() {
+		let request = CaptureImage {
+			format: CaptureImageFormat::Zpixmap,
+			target: Drawable::new(1),
+			area: Rectangle::new(Px(0), Px(0), Px(2), Px(2)),
+			plane_mask: u32::MAX,
+		};
+
+		assert_eq!(CaptureImage::MAJOR_OPCODE, 73);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(
+			bytes,
+			[
+				73, 2, 0, 5, // header
+				0, 0, 0, 1, // target
+				0, 0, 0, 0, 0, 2, 0, 2, // area
+				0xff, 0xff, 0xff, 0xff, // plane_mask
+			],
+		);
+	}
+
+	#[test]
+	fn test_capture_image_format_values() {
+		assert_eq!(write(&CaptureImageFormat::XyPixmap), [1]);
+		assert_eq!(write(&CaptureImageFormat::Zpixmap), [2]);
+		assert_eq!(write(&PlaceImageFormat::XyPixmap), [1]);
+		assert_eq!(write(&PlaceImageFormat::Zpixmap), [2]);
+	}
+
+	#[test]
+	fn test_place_image_correct_length() {
+		// 3 pixels of 32 bits each, padded to 32 bits: 12 bytes per scanline.
+		assert!(place_image(PlaceImageFormat::Zpixmap, 3, 2, 24, 24).is_ok());
+		// 33 bits, padded to 32 bits: 8 bytes per scanline.
+		assert!(place_image(PlaceImageFormat::Bitmap, 33, 2, 1, 16).is_ok());
+	}
+
+	#[test]
+	fn test_place_image_undersized() {
+		assert_eq!(
+			place_image(PlaceImageFormat::Zpixmap, 3, 2, 24, 20).unwrap_err(),
+			PlaceImageDataError::WrongLength {
+				expected: 24,
+				found: 20,
+			}
+		);
+		assert_eq!(
+			place_image(PlaceImageFormat::XyPixmap, 33, 2, 24, 16).unwrap_err(),
+			PlaceImageDataError::WrongLength {
+				expected: 384,
+				found: 16,
+			}
+		);
+		assert_eq!(
+			place_image(PlaceImageFormat::Zpixmap, 3, 2, 8, 6).unwrap_err(),
+			PlaceImageDataError::UnsupportedDepth(8)
+		);
+	}
+
+	#[test]
+	fn test_place_image_too_wide() {
+		let result = PlaceImage::new_checked(
+			PlaceImageFormat::Bitmap,
+			Drawable::new(1),
+			GraphicsContext::new(2),
+			Dimensions::new(Px(u16::MAX), Px(1)),
+			Coords::new(Px(0), Px(0)),
+			1,
+			1,
+			vec![],
+			&ConnectionSuccess::test_setup(ImageEndianness::LittleEndian),
+		);
+
+		assert_eq!(
+			result.unwrap_err(),
+			PlaceImageDataError::TooWide {
+				width: u16::MAX,
+				left_padding: 1,
+			}
+		);
+	}
+}
@@ -12,6 +12,7 @@
 
 extern crate self as xrb;
 
+use thiserror::Error;
 use xrbk::{pad, ConstantX11Size};
 use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
 
@@ -21,6 +22,7 @@ use crate::{
 	unit::Px,
 	visual::RgbColor,
 	x11::{error, reply},
+	Coords,
 	CursorAppearance,
 	Dimensions,
 	Drawable,
@@ -177,6 +179,70 @@ derive_xrb! {
 	}
 }
 
+/// An error returned when the dimensions given for a
+/// [`CreatePixmap` request] would generate a [`Value` error].
+///
+/// [`CreatePixmap` request]: CreatePixmap
+/// [`Value` error]: error::Value
+#[derive(Debug, Hash, PartialEq, Eq, Error)]
+pub enum PixmapDimensionsError {
+	/// The width of the [pixmap] is zero.
+	///
+	/// [pixmap]: Pixmap
+	#[error("the width of a pixmap must not be zero")]
+	ZeroWidth,
+
+	/// The height of the [pixmap] is zero.
+	///
+	/// [pixmap]: Pixmap
+	#[error("the height of a pixmap must not be zero")]
+	ZeroHeight,
+}
+
+impl CreatePixmap {
+	/// Creates a new `CreatePixmap` [request], verifying that the `dimensions`
+	/// would not generate a [`Value` error].
+	///
+	/// # Errors
+	/// Returns [`PixmapDimensionsError::ZeroWidth`] if the width is zero, or
+	/// [`PixmapDimensionsError::ZeroHeight`] if the height is zero.
+	///
+	/// [request]: Request
+	/// [`Value` error]: error::Value
+	pub const fn new(
+		depth: u8, pixmap_id: Pixmap, drawable: Drawable, dimensions: Dimensions,
+	) -> Result<Self, PixmapDimensionsError> {
+		let Dimensions { width, height } = dimensions;
+
+		if width.0 == 0 {
+			return Err(PixmapDimensionsError::ZeroWidth);
+		}
+
+		if height.0 == 0 {
+			return Err(PixmapDimensionsError::ZeroHeight);
+		}
+
+		Ok(Self {
+			depth,
+			pixmap_id,
+			drawable,
+			width,
+			height,
+		})
+	}
+
+	/// The dimensions of the [pixmap].
+	///
+	/// [pixmap]: Pixmap
+	#[must_use]
+	pub const fn dimensions(&self) -> Dimensions {
+		Dimensions {
+			width: self.width,
+			height: self.height,
+		}
+	}
+}
+
 request_error! {
 	pub enum CreateGraphicsContextError for CreateGraphicsContext {
 		Drawable,
@@ -422,6 +488,10 @@ derive_xrb! {
 	/// A [`GraphicsContext` error] is generated if `target` does not refer to a
 	/// defined [`GraphicsContext`].
 	///
+	/// A [`Value` error] is generated if `dashes` is empty or any of its
+	/// elements are zero. See [`SetDashes::new`] to catch this before the
+	/// [request] is sent.
+	///
 	/// [request]: Request
 	///
 	/// [`dashes`]: GraphicsOptions::dashes
@@ -430,6 +500,7 @@ derive_xrb! {
 	/// [`ChangeGraphicsOptions` request]: ChangeGraphicsOptions
 	///
 	/// [`GraphicsContext` error]: error::GraphicsContext
+	/// [`Value` error]: error::Value
 	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 	pub struct SetDashes: Request(58, SetDashesError) {
 		/// The [`GraphicsContext`] on which this [request] configures its
@@ -454,12 +525,73 @@ derive_xrb! {
 		/// Each element represents the length of a dash in the pattern,
 		/// measured in pixels. A `dashes` list of odd length is appended to
 		/// itself to produce a list of even length.
+		///
+		/// # Errors
+		/// A [`Value` error] is generated if this is empty or any of its
+		/// elements are zero.
+		///
+		/// [`Value` error]: error::Value
 		#[context(dashes_len => usize::from(*dashes_len))]
 		pub dashes: Vec<Px<u8>>,
 		[_; dashes => pad(dashes)],
 	}
 }
 
+/// An error returned when the `dashes` given for a [`SetDashes` request] would
+/// generate a [`Value` error].
+///
+/// [`SetDashes` request]: SetDashes
+/// [`Value` error]: error::Value
+#[derive(Debug, Hash, PartialEq, Eq, Error)]
+pub enum DashesError {
+	/// No dashes were given.
+	#[error("the list of dashes must not be empty")]
+	Empty,
+
+	/// The dash at the given index has a length of zero.
+	#[error("the dash at index {0} has a length of zero")]
+	ZeroLength(usize),
+
+	/// The given number of dashes is more than fits in the request's `u16`
+	/// dash count.
+	#[error("{0} dashes were given, but there can be at most 65535")]
+	TooMany(usize),
+}
+
+impl SetDashes {
+	/// Creates a new `SetDashes` [request], verifying that the `dashes` would
+	/// not generate a [`Value` error].
+	///
+	/// # Errors
+	/// Returns [`DashesError::Empty`] if `dashes` is empty,
+	/// [`DashesError::TooMany`] if there are more than [`u16::MAX`] `dashes`,
+	/// or [`DashesError::ZeroLength`] if any of the `dashes` are zero.
+	///
+	/// [request]: Request
+	/// [`Value` error]: error::Value
+	pub fn new(
+		target: GraphicsContext, dash_offset: Px<u16>, dashes: Vec<Px<u8>>,
+	) -> Result<Self, DashesError> {
+		if dashes.is_empty() {
+			return Err(DashesError::Empty);
+		}
+
+		if dashes.len() > usize::from(u16::MAX) {
+			return Err(DashesError::TooMany(dashes.len()));
+		}
+
+		if let Some(index) = dashes.iter().position(|Px(dash)| *dash == 0) {
+			return Err(DashesError::ZeroLength(index));
+		}
+
+		Ok(Self {
+			target,
+			dash_offset,
+			dashes,
+		})
+	}
+}
+
 request_error! {
 	pub enum SetClipRectanglesError for SetClipRectangles {
 		GraphicsContext,
@@ -474,6 +606,7 @@ request_error! {
 /// [rectangles]: Rectangle
 ///
 /// [`SetClipRectangles` request]: SetClipRectangles
+#[doc(alias("Ordering"))]
 #[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum ClipRectanglesOrdering {
 	/// No particular order is specified.
@@ -498,6 +631,7 @@ pub enum ClipRectanglesOrdering {
 	/// [rectangles]: Rectangle
 	///
 	/// [`SetClipRectangles` request]: SetClipRectangles
+	#[doc(alias("YSorted"))]
 	SortedByY,
 
 	/// [Rectangles][rectangles] are ordered primarily by their y coordinate,
@@ -511,6 +645,7 @@ pub enum ClipRectanglesOrdering {
 	/// [rectangles]: Rectangle
 	///
 	/// [`SetClipRectangles` request]: SetClipRectangles
+	#[doc(alias("YxSorted"))]
 	SortedByYx,
 
 	/// [Rectangles][rectangles] are ordered primarily by their y coordinate,
@@ -527,6 +662,7 @@ pub enum ClipRectanglesOrdering {
 	/// [rectangles]: Rectangle
 	///
 	/// [`SetClipRectangles` request]: SetClipRectangles
+	#[doc(alias("YxBanded"))]
 	BandedByYx,
 }
 
@@ -604,6 +740,7 @@ derive_xrb! {
 		/// effect.
 		///
 		/// [rectangles]: Rectangle
+		#[doc(alias("rectangles"))]
 		#[context(self::remaining => remaining / Rectangle::X11_SIZE)]
 		pub clip_rectangles: Vec<Rectangle>,
 	}
@@ -638,6 +775,21 @@ derive_xrb! {
 	}
 }
 
+impl SetClipRectangles {
+	/// Returns the coordinates of the top-left corner of the clip mask, made
+	/// up of the `clip_x` and `clip_y`.
+	///
+	/// The coordinates used in the [rectangles] in `clip_rectangles` are
+	/// relative to these coordinates.
+	///
+	/// [rectangles]: Rectangle
+	#[doc(alias("clip_origin"))]
+	#[must_use]
+	pub const fn clip_coords(&self) -> Coords {
+		Coords::new(self.clip_x, self.clip_y)
+	}
+}
+
 request_error! {
 	#[doc(alias("CreateCursorError"))]
 	pub enum CreateCursorAppearanceError for CreateCursorAppearance {
@@ -1029,6 +1181,7 @@ derive_xrb! {
 	/// [request]: Request
 	///
 	/// [`CursorAppearance` error]: error::CursorAppearance
+	#[doc(alias("RecolorCursor"))]
 	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 	pub struct RecolorCursorAppearance: Request(96, error::CursorAppearance) {
 		/// The [`CursorAppearance`] which is to be recolored.
@@ -1065,6 +1218,24 @@ derive_xrb! {
 	}
 }
 
+impl CursorAppearance {
+	/// Creates a [`RecolorCursorAppearance` request] which changes the
+	/// foreground and background colors of this `CursorAppearance`.
+	///
+	/// [`RecolorCursorAppearance` request]: RecolorCursorAppearance
+	#[must_use]
+	pub const fn recolor(
+		self, foreground_color: RgbColor, background_color: RgbColor,
+	) -> RecolorCursorAppearance {
+		RecolorCursorAppearance {
+			target: self,
+
+			foreground_color,
+			background_color,
+		}
+	}
+}
+
 request_error! {
 	#[doc(alias("QueryBestSizeError"))]
 	pub enum QueryIdealDimensionsError for QueryIdealDimensions {
@@ -1183,3 +1354,259 @@ derive_xrb! {
 		pub dimensions: Dimensions,
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+	use crate::set::{Function, LineWidth};
+
+	#[test]
+	fn test_create_cursor_appearance_round_trip() {
+		let request = CreateCursorAppearance {
+			cursor_appearance_id: CursorAppearance::new(0x0040_0010),
+			source: Pixmap::new(0x0040_0011),
+			mask: None,
+			foreground_color: RgbColor::from_rgb8(0xff, 0xff, 0xff),
+			background_color: RgbColor::from_rgb8(0x00, 0x00, 0x00),
+			hotspot_x: Px(4),
+			hotspot_y: Px(8),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[..4], [93, 0, 0, 8]);
+		// `mask` is `None`.
+		assert_eq!(bytes[12..16], [0; 4]);
+	}
+
+	#[test]
+	fn test_create_glyph_cursor_appearance_round_trip() {
+		let request = CreateGlyphCursorAppearance {
+			cursor_appearance_id: CursorAppearance::new(0x0040_0010),
+			source_font: Font::new(0x0040_0012),
+			mask_font: Some(Font::new(0x0040_0012)),
+			source_char: 68,
+			mask_char: Some(69),
+			foreground_color: RgbColor::from_rgb8(0x00, 0x00, 0x00),
+			background_color: RgbColor::from_rgb8(0xff, 0xff, 0xff),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 32);
+		assert_eq!(bytes[..4], [94, 0, 0, 8]);
+		assert_eq!(bytes[16..20], [0, 68, 0, 69]);
+	}
+
+	#[test]
+	fn test_recolor_cursor_appearance_round_trip() {
+		let cursor_appearance = CursorAppearance::new(0x0040_0010);
+		let request = cursor_appearance.recolor(
+			RgbColor::from_rgb8(0xff, 0x00, 0x00),
+			RgbColor::from_rgb8(0x00, 0x00, 0xff),
+		);
+
+		assert_eq!(request.target, cursor_appearance);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 20);
+		assert_eq!(bytes[..4], [96, 0, 0, 5]);
+		assert_eq!(bytes[8..14], [0xff, 0xff, 0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_query_ideal_dimensions_round_trip() {
+		assert_roundtrip!(
+			QueryIdealDimensions {
+				class: DimensionClass::CursorAppearance,
+				drawable: Drawable::new(0x0000_0100),
+				dimensions: Dimensions::new(Px(16), Px(16)),
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_change_graphics_options_round_trip() {
+		let mut builder = GraphicsOptions::builder();
+		builder
+			.function(Function::Xor)
+			.line_width(LineWidth::new(3))
+			.graphics_exposure(false);
+
+		let request = ChangeGraphicsOptions {
+			target: GraphicsContext::new(0x0040_0005),
+			changed_options: builder.build(),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		// 12 byte header and three 4-byte values.
+		assert_eq!(bytes.len(), 24);
+		assert_eq!(bytes[..4], [56, 0, 0, 6]);
+		// `FUNCTION | LINE_WIDTH | GRAPHICS_EXPOSURE`.
+		assert_eq!(bytes[8..12], [0x00, 0x01, 0x00, 0x11]);
+		assert_eq!(bytes[12..], [0, 0, 0, 6, 0, 0, 0, 3, 0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_copy_graphics_options_round_trip() {
+		let request = CopyGraphicsOptions {
+			source: GraphicsContext::new(0x0040_0005),
+			destination: GraphicsContext::new(0x0040_0006),
+			options_mask: GraphicsOptionsMask::FOREGROUND_COLOR
+				| GraphicsOptionsMask::BACKGROUND_COLOR,
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), CopyGraphicsOptions::X11_SIZE);
+		assert_eq!(
+			bytes,
+			[
+				57, 0, 0, 4, // header
+				0x00, 0x40, 0x00, 0x05, // source
+				0x00, 0x40, 0x00, 0x06, // destination
+				0x00, 0x00, 0x00, 0x0c, // options_mask
+			],
+		);
+	}
+
+	#[test]
+	fn test_destroy_graphics_context_round_trip() {
+		let request = DestroyGraphicsContext {
+			target: GraphicsContext::new(0x0040_0005),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [60, 0, 0, 2, 0x00, 0x40, 0x00, 0x05]);
+	}
+
+	#[test]
+	fn test_set_dashes_round_trip() {
+		let request = SetDashes::new(
+			GraphicsContext::new(0x0040_0005),
+			Px(2),
+			vec![Px(4), Px(2), Px(1)],
+		)
+		.unwrap();
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		// 12 byte header and three dashes padded to 4 bytes.
+		assert_eq!(bytes.len(), 16);
+		assert_eq!(bytes[..4], [58, 0, 0, 4]);
+		assert_eq!(bytes[8..], [0, 2, 0, 3, 4, 2, 1, 0]);
+	}
+
+	#[test]
+	fn test_create_pixmap_round_trip() {
+		let dimensions = Dimensions::new(Px(640), Px(480));
+		let create = CreatePixmap::new(
+			24,
+			Pixmap::new(0x0040_0002),
+			Drawable::new(0x0000_0100),
+			dimensions,
+		)
+		.unwrap();
+
+		assert_eq!(create.dimensions(), dimensions);
+		assert_roundtrip!(create, skip = 1);
+
+		let bytes = write(&create);
+
+		assert_eq!(
+			bytes,
+			[
+				53, 24, 0, 4, // header
+				0x00, 0x40, 0x00, 0x02, // pixmap_id
+				0x00, 0x00, 0x01, 0x00, // drawable
+				0x02, 0x80, 0x01, 0xe0, // width, height
+			],
+		);
+
+		assert_roundtrip!(
+			FreePixmap {
+				target: Pixmap::new(0x0040_0002),
+			},
+			skip = 1,
+		);
+	}
+
+	#[test]
+	fn test_create_pixmap_invalid_dimensions() {
+		let pixmap = Pixmap::new(0x0040_0002);
+		let drawable = Drawable::new(0x0000_0100);
+
+		assert_eq!(
+			CreatePixmap::new(24, pixmap, drawable, Dimensions::new(Px(0), Px(480))),
+			Err(PixmapDimensionsError::ZeroWidth),
+		);
+		assert_eq!(
+			CreatePixmap::new(24, pixmap, drawable, Dimensions::new(Px(640), Px(0))),
+			Err(PixmapDimensionsError::ZeroHeight),
+		);
+	}
+
+	#[test]
+	fn test_set_dashes_invalid() {
+		let target = GraphicsContext::new(0x0040_0005);
+
+		assert_eq!(
+			SetDashes::new(target, Px(0), vec![]),
+			Err(DashesError::Empty)
+		);
+		assert_eq!(
+			SetDashes::new(target, Px(0), vec![Px(3), Px(0)]),
+			Err(DashesError::ZeroLength(1))
+		);
+		assert_eq!(
+			SetDashes::new(target, Px(0), vec![Px(1); 65536]),
+			Err(DashesError::TooMany(65536))
+		);
+
+		// The most dashes that fit in the dash count are accepted.
+		assert!(SetDashes::new(target, Px(0), vec![Px(1); 65535]).is_ok());
+	}
+
+	#[test]
+	fn test_set_clip_rectangles_round_trip() {
+		let request = SetClipRectangles {
+			ordering: ClipRectanglesOrdering::BandedByYx,
+			target: GraphicsContext::new(0x0040_0005),
+			clip_x: Px(10),
+			clip_y: Px(-10),
+			clip_rectangles: vec![
+				Rectangle::new(Px(0), Px(0), Px(50), Px(20)),
+				Rectangle::new(Px(60), Px(0), Px(50), Px(20)),
+			],
+		};
+
+		assert_roundtrip!(request, skip = 1);
+		assert_eq!(request.clip_coords(), Coords::new(Px(10), Px(-10)));
+
+		let bytes = write(&request);
+
+		// 12 byte header and two 8-byte rectangles.
+		assert_eq!(bytes.len(), 28);
+		assert_eq!(bytes[..4], [59, 3, 0, 7]);
+	}
+}
@@ -21,6 +21,7 @@ use xrbk::{
 	ReadResult,
 	Readable,
 	Writable,
+	WriteError,
 	WriteResult,
 	X11Size,
 };
@@ -33,9 +34,8 @@ use thiserror::Error;
 use crate::{
 	message::Request,
 	set::KeyboardOptions,
-	unit::{Px, SignedPercentage},
+	unit::{Px, SignedPercentage, ValueOutOfBounds},
 	x11::{error, reply},
-	Any,
 	AnyModifierKeyMask,
 	Button,
 	Coords,
@@ -46,6 +46,7 @@ use crate::{
 	FreezeMode,
 	Keycode,
 	Keysym,
+	Specificity,
 	Window,
 };
 
@@ -236,6 +237,27 @@ derive_xrb! {
 	}
 }
 
+impl UngrabCursor {
+	/// Creates an `UngrabCursor` [request] which releases the cursor grab at
+	/// the given `time`.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub const fn new(time: CurrentableTime) -> Self {
+		Self { time }
+	}
+
+	/// Creates an `UngrabCursor` [request] which releases the cursor grab at
+	/// the X server's [current time].
+	///
+	/// [request]: Request
+	/// [current time]: CurrentableTime::CurrentTime
+	#[must_use]
+	pub const fn now() -> Self {
+		Self::new(CurrentableTime::CurrentTime)
+	}
+}
+
 request_error! {
 	pub enum GrabButtonError for GrabButton {
 		Access,
@@ -381,8 +403,8 @@ derive_xrb! {
 		///
 		/// [button]: Button
 		///
-		/// [`Any`]: Any::Any
-		pub button: Any<Button>,
+		/// [`Any`]: Specificity::Any
+		pub button: Specificity<Button>,
 		_,
 
 		/// The combination of modifiers which must be held for a press of the
@@ -430,9 +452,9 @@ derive_xrb! {
 		///
 		/// [passive button grab]: GrabButton
 		///
-		/// [`Any`]: Any::Any
+		/// [`Any`]: Specificity::Any
 		#[metabyte]
-		pub button: Any<Button>,
+		pub button: Specificity<Button>,
 
 		/// The [window] on which the [passive button grab] was established.
 		///
@@ -525,6 +547,37 @@ derive_xrb! {
 	}
 }
 
+impl ChangeActiveCursorGrab {
+	/// Creates a `ChangeActiveCursorGrab` [request] which changes the
+	/// `event_mask` of the [active cursor grab] at the given `time`.
+	///
+	/// The `cursor_appearance` is [`None`]; it can be overridden with
+	/// [`with_cursor_appearance`].
+	///
+	/// [request]: Request
+	/// [active cursor grab]: GrabCursor
+	///
+	/// [`with_cursor_appearance`]: ChangeActiveCursorGrab::with_cursor_appearance
+	#[must_use]
+	pub const fn new(event_mask: CursorEventMask, time: CurrentableTime) -> Self {
+		Self {
+			cursor_appearance: None,
+			time,
+			event_mask,
+		}
+	}
+
+	/// Overrides the appearance of the cursor for the duration of the grab.
+	#[must_use]
+	pub const fn with_cursor_appearance(
+		mut self, cursor_appearance: Option<CursorAppearance>,
+	) -> Self {
+		self.cursor_appearance = cursor_appearance;
+
+		self
+	}
+}
+
 request_error! {
 	pub enum GrabKeyboardError for GrabKeyboard {
 		Value,
@@ -641,6 +694,27 @@ derive_xrb! {
 	}
 }
 
+impl UngrabKeyboard {
+	/// Creates an `UngrabKeyboard` [request] which releases the keyboard grab
+	/// at the given `time`.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub const fn new(time: CurrentableTime) -> Self {
+		Self { time }
+	}
+
+	/// Creates an `UngrabKeyboard` [request] which releases the keyboard grab
+	/// at the X server's [current time].
+	///
+	/// [request]: Request
+	/// [current time]: CurrentableTime::CurrentTime
+	#[must_use]
+	pub const fn now() -> Self {
+		Self::new(CurrentableTime::CurrentTime)
+	}
+}
+
 request_error! {
 	pub enum GrabKeyError for GrabKey {
 		Access,
@@ -722,8 +796,8 @@ derive_xrb! {
 		///
 		/// [button]: Button
 		///
-		/// [`Any`]: Any::Any
-		pub key: Any<Keycode>,
+		/// [`Any`]: Specificity::Any
+		pub key: Specificity<Keycode>,
 
 		/// The [freeze mode] applied to the cursor.
 		///
@@ -796,9 +870,9 @@ derive_xrb! {
 		///
 		/// [passive key grab]: GrabKey
 		///
-		/// [`Any`]: Any::Any
+		/// [`Any`]: Specificity::Any
 		#[metabyte]
-		pub key: Any<Keycode>,
+		pub key: Specificity<Keycode>,
 
 		/// The [window] on which the [passive key grab] was established.
 		///
@@ -834,10 +908,14 @@ derive_xrb! {
 /// [`AllowEvents` request].
 ///
 /// [`AllowEvents` request]: AllowEvents
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum AllowEventsMode {
 	/// Unfreezes the cursor if it is frozen and you have active grab on the
 	/// cursor.
+	///
+	/// This is the default mode.
+	#[default]
+	#[doc(alias = "AsyncPointer")]
 	UnfreezeCursor,
 	/// Unfreezes the cursor, but freezes it again after the next
 	/// [`ButtonPress`] or [`ButtonRelease`].
@@ -850,6 +928,7 @@ pub enum AllowEventsMode {
 	///
 	/// [`ButtonPress`]: crate::x11::event::ButtonPress
 	/// [`ButtonRelease`]: crate::x11::event::ButtonRelease
+	#[doc(alias = "SyncPointer")]
 	RefreezeCursor,
 	/// If the cursor is frozen as a result of the activation of a passive grab
 	/// or [`RefreezeCursor`] mode from your client, the grab is released and
@@ -858,10 +937,12 @@ pub enum AllowEventsMode {
 	/// [`RefreezeCursor`]: AllowEventsMode::RefreezeCursor
 	///
 	/// [event]: crate::message::Event
+	#[doc(alias = "ReplayPointer")]
 	ReplayCursor,
 
 	/// Unfreezes the keyboard if it is frozen and you have an active grab on
 	/// the keyboard.
+	#[doc(alias = "AsyncKeyboard")]
 	UnfreezeKeyboard,
 	/// Unfreezes the keyboard, but freezes it again after the next
 	/// [`KeyPress`] or [`KeyPress`].
@@ -874,6 +955,7 @@ pub enum AllowEventsMode {
 	///
 	/// [`KeyPress`]: crate::x11::event::KeyPress
 	/// [`KeyRelease`]: crate::x11::event::KeyRelease
+	#[doc(alias = "SyncKeyboard")]
 	RefreezeKeyboard,
 	/// If the keyboard is frozen as a result of the activation of a passive
 	/// grab or [`RefreezeKeyboard`] mode from your client, the grab is released
@@ -886,6 +968,7 @@ pub enum AllowEventsMode {
 
 	/// If both the cursor and the keyboard are frozen by your client, both are
 	/// unfrozen.
+	#[doc(alias = "AsyncBoth")]
 	UnfreezeBoth,
 	/// If both the cursor and the keyboard are frozen by your client, both are
 	/// unfrozen but are both frozen again on the next button or key press or
@@ -900,6 +983,7 @@ pub enum AllowEventsMode {
 	///
 	/// [`KeyPress`]: crate::x11::event::KeyPress
 	/// [`KeyRelease`]: crate::x11::event::KeyRelease
+	#[doc(alias = "SyncBoth")]
 	RefreezeBoth,
 }
 
@@ -954,7 +1038,7 @@ derive_xrb! {
 	/// [request]: Request
 	///
 	/// [`Window` error]: error::Window
-	#[doc(alias("QueryPointer, QueryCursor, GetCursorPos, GetCursorLocation"))]
+	#[doc(alias("QueryPointer", "QueryCursor", "GetCursorPos", "GetCursorLocation"))]
 	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable, ConstantX11Size)]
 	pub struct QueryCursorLocation: Request(38, error::Window) -> reply::QueryCursorLocation {
 		/// Specifies a [window] to receive relative coordinates of the cursor
@@ -1009,6 +1093,27 @@ derive_xrb! {
 	}
 }
 
+impl AllowEvents {
+	/// Creates an `AllowEvents` [request] with the given `mode` at the X
+	/// server's [current time].
+	///
+	/// [request]: Request
+	/// [current time]: CurrentableTime::CurrentTime
+	#[must_use]
+	pub const fn now(mode: AllowEventsMode) -> Self {
+		Self {
+			mode,
+			time: CurrentableTime::CurrentTime,
+		}
+	}
+}
+
+impl Default for AllowEvents {
+	fn default() -> Self {
+		Self::now(AllowEventsMode::default())
+	}
+}
+
 derive_xrb! {
 	/// A [request] that converts coordinates relative to the given `original`
 	/// [window] to `output_coords` relative to the given `output` [window].
@@ -1185,6 +1290,51 @@ derive_xrb! {
 	}
 }
 
+impl WarpCursor {
+	/// Creates a `WarpCursor` [request] which offsets the cursor by `dx` and
+	/// `dy` from its current location.
+	///
+	/// Both the `source` and `destination` are [`None`], so the cursor is
+	/// warped wherever it currently is.
+	///
+	/// [request]: Request
+	#[must_use]
+	pub const fn relative(dx: Px<i16>, dy: Px<i16>) -> Self {
+		Self {
+			source: None,
+			destination: None,
+
+			source_coords: Coords::new(Px(0), Px(0)),
+			source_width: WarpSourceDimension::FillRemaining,
+			source_height: WarpSourceDimension::FillRemaining,
+
+			coords: Coords::new(dx, dy),
+		}
+	}
+
+	/// Creates a `WarpCursor` [request] which moves the cursor to `x` and `y`
+	/// relative to the top-left corner of the `destination` [window].
+	///
+	/// The `source` is [`None`], so the cursor is warped wherever it
+	/// currently is.
+	///
+	/// [request]: Request
+	/// [window]: Window
+	#[must_use]
+	pub const fn to_window(destination: Window, x: Px<i16>, y: Px<i16>) -> Self {
+		Self {
+			source: None,
+			destination: Some(destination),
+
+			source_coords: Coords::new(Px(0), Px(0)),
+			source_width: WarpSourceDimension::FillRemaining,
+			source_height: WarpSourceDimension::FillRemaining,
+
+			coords: Coords::new(x, y),
+		}
+	}
+}
+
 request_error! {
 	pub enum SetFocusError for SetFocus {
 		Match,
@@ -1200,6 +1350,7 @@ request_error! {
 /// [window]: Window
 ///
 /// [`SetFocus` request]: SetFocus
+#[doc(alias = "RevertTo")]
 #[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 pub enum RevertFocus {
 	/// Revert the focus to no [window].
@@ -1216,6 +1367,7 @@ pub enum RevertFocus {
 	/// time.
 	///
 	/// [window]: Window
+	#[doc(alias = "PointerRoot")]
 	CursorRoot,
 	/// Revert the focus to the parent of the [window] which the cursor is in at
 	/// the time.
@@ -1414,6 +1566,11 @@ impl<const KEYSYMS_PER_KEYCODE: usize> Request for ChangeKeyboardMapping<KEYSYMS
 
 	const MAJOR_OPCODE: u8 = 100;
 	const MINOR_OPCODE: Option<u16> = None;
+	#[allow(clippy::cast_possible_truncation)]
+	fn metabyte(&self) -> Result<u8, WriteError> {
+		// Length of `mappings`.
+		Ok(self.mappings.len() as u8)
+	}
 }
 
 impl<const KEYSYMS_PER_KEYCODE: usize> X11Size for ChangeKeyboardMapping<KEYSYMS_PER_KEYCODE> {
@@ -1444,7 +1601,7 @@ impl<const KEYSYMS_PER_KEYCODE: usize> Writable for ChangeKeyboardMapping<KEYSYM
 		// The major opcode.
 		Self::MAJOR_OPCODE.write_to(buf)?;
 		// Length of `mappings`.
-		(self.mappings.len() as u8).write_to(buf)?;
+		self.metabyte()?.write_to(buf)?;
 		// The length of the message.
 		self.length().write_to(buf)?;
 
@@ -1686,10 +1843,31 @@ derive_xrb! {
 		///
 		/// [`bell_volume`]: KeyboardOptions::bell_volume
 		#[doc(alias("percent"))]
+		#[metabyte]
 		pub volume: SignedPercentage,
 	}
 }
 
+impl RingBell {
+	/// Creates a new `RingBell` [request] with the given `volume`, relative to
+	/// the base [`bell_volume`].
+	///
+	/// # Errors
+	/// Returns a [`ValueOutOfBounds`] error if `volume < -100` or
+	/// `volume > 100`. The X server would otherwise generate a [`Value` error].
+	///
+	/// [request]: Request
+	///
+	/// [`bell_volume`]: KeyboardOptions::bell_volume
+	/// [`Value` error]: error::Value
+	pub const fn new(volume: i8) -> Result<Self, ValueOutOfBounds<i8>> {
+		match SignedPercentage::new(volume) {
+			Ok(volume) => Ok(Self { volume }),
+			Err(error) => Err(error),
+		}
+	}
+}
+
 /// Represents a type that may be chosen as its default value.
 #[derive(Debug, Hash, PartialEq, Eq)]
 pub enum OrDefault<T> {
@@ -1781,6 +1959,67 @@ impl Fraction<OrDefault<Px<u8>>> {
 			denominator => Ok(Self(numerator, denominator)),
 		}
 	}
+
+	/// Creates the [`Fraction`] which most closely approximates the given
+	/// `ratio`.
+	///
+	/// Both the numerator and denominator of a `Fraction<OrDefault<Px<u8>>>`
+	/// are limited to 255, so the closest fraction within those limits is
+	/// chosen. If there are multiple equally close fractions, the one with the
+	/// smallest denominator is chosen.
+	///
+	/// Returns [`None`] if `ratio` is negative, greater than 255, or not
+	/// finite.
+	#[must_use]
+	pub fn from_ratio(ratio: f32) -> Option<Self> {
+		if !ratio.is_finite() || !(0.0..=f32::from(u8::MAX)).contains(&ratio) {
+			return None;
+		}
+
+		let mut closest = (0, 1);
+		let mut closest_error = f32::INFINITY;
+
+		for denominator in 1..=u8::MAX {
+			let numerator = (ratio * f32::from(denominator))
+				.round()
+				.min(f32::from(u8::MAX));
+			let error = (numerator / f32::from(denominator) - ratio).abs();
+
+			if error < closest_error {
+				#[allow(
+					clippy::cast_possible_truncation,
+					clippy::cast_sign_loss,
+					reason = "`numerator` is rounded and clamped to `0..=255`"
+				)]
+				let numerator = numerator as u8;
+
+				closest = (numerator, denominator);
+				closest_error = error;
+			}
+		}
+
+		let (numerator, denominator) = closest;
+
+		Some(Self(
+			OrDefault::Other(Px(numerator)),
+			OrDefault::Other(Px(denominator)),
+		))
+	}
+
+	/// Returns the value of this fraction as a ratio.
+	///
+	/// Returns [`None`] if either the numerator or the denominator is
+	/// [`OrDefault::Default`].
+	#[must_use]
+	pub fn as_ratio(&self) -> Option<f32> {
+		match self {
+			Self(OrDefault::Other(Px(numerator)), OrDefault::Other(Px(denominator))) => {
+				Some(f32::from(*numerator) / f32::from(*denominator))
+			},
+
+			_ => None,
+		}
+	}
 }
 
 impl Fraction<Px<u16>> {
@@ -1794,6 +2033,14 @@ impl Fraction<Px<u16>> {
 			denominator => Ok(Self(numerator, denominator)),
 		}
 	}
+
+	/// Returns the value of this fraction as a ratio.
+	#[must_use]
+	pub fn as_ratio(&self) -> f32 {
+		let Self(Px(numerator), Px(denominator)) = self;
+
+		f32::from(*numerator) / f32::from(*denominator)
+	}
 }
 
 derive_xrb! {
@@ -2019,6 +2266,11 @@ impl Request for SetModifierMapping {
 
 	const MAJOR_OPCODE: u8 = 118;
 	const MINOR_OPCODE: Option<u16> = None;
+	#[allow(clippy::cast_possible_truncation)]
+	fn metabyte(&self) -> Result<u8, WriteError> {
+		// The number of keycodes per modifier.
+		Ok(self.max_keycodes_len() as u8)
+	}
 }
 
 impl X11Size for SetModifierMapping {
@@ -2080,6 +2332,13 @@ impl Writable for SetModifierMapping {
 
 		let buf = &mut buf.limit(HEADER + (8 * keycodes_size));
 
+		// The major opcode.
+		Self::MAJOR_OPCODE.write_to(buf)?;
+		// The number of keycodes per modifier.
+		self.metabyte()?.write_to(buf)?;
+		// The length of the message.
+		self.length().write_to(buf)?;
+
 		// For each keycodes field, we want to make sure that they are written
 		// as the same length as the longest list. Fortunately, that is easy to
 		// do, because (a) the order of each list does not matter, and (b) a `0`
@@ -2131,3 +2390,359 @@ derive_xrb! {
 	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 	pub struct GetModifierMapping: Request(119) -> reply::GetModifierMapping;
 }
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_grab_button_any() {
+		let grab = GrabButton {
+			owner_events: true,
+			grab_window: Window::new(0x0040_0001),
+			event_mask: CursorEventMask::BUTTON_PRESS,
+			cursor_freeze: FreezeMode::Unfrozen,
+			keyboard_freeze: FreezeMode::Frozen,
+			confine_to: None,
+			cursor_appearance: None,
+			button: Specificity::Any,
+			modifiers: AnyModifierKeyMask::ANY_MODIFIER,
+		};
+
+		assert_roundtrip!(grab, skip = 1);
+		assert_eq!(
+			write(&grab),
+			[
+				28, 1, 0, 6, // header
+				0x00, 0x40, 0x00, 0x01, // grab_window
+				0x00, 0x04, // event_mask
+				1, 0, // cursor_freeze, keyboard_freeze
+				0, 0, 0, 0, // confine_to
+				0, 0, 0, 0, // cursor_appearance
+				0, 0, // button, unused
+				0x80, 0x00, // modifiers
+			],
+		);
+	}
+
+	#[test]
+	fn test_grab_button_specific() {
+		let grab = GrabButton {
+			owner_events: false,
+			grab_window: Window::new(0x0040_0001),
+			event_mask: CursorEventMask::BUTTON_PRESS | CursorEventMask::BUTTON_RELEASE,
+			cursor_freeze: FreezeMode::Frozen,
+			keyboard_freeze: FreezeMode::Unfrozen,
+			confine_to: Some(Window::new(0x0040_0002)),
+			cursor_appearance: None,
+			button: Specificity::Other(Button::new(3)),
+			modifiers: AnyModifierKeyMask::CONTROL | AnyModifierKeyMask::SHIFT,
+		};
+
+		assert_roundtrip!(grab, skip = 1);
+
+		let bytes = write(&grab);
+		assert_eq!(bytes[20], 3);
+		assert_eq!(bytes[22..], [0x00, 0x05]);
+	}
+
+	#[test]
+	fn test_ungrab_round_trip() {
+		assert_roundtrip!(UngrabCursor::now(), skip = 1);
+		assert_eq!(write(&UngrabCursor::now()), [27, 0, 0, 2, 0, 0, 0, 0]);
+
+		let time = CurrentableTime::Other(crate::Timestamp::new(0x1234));
+
+		assert_roundtrip!(UngrabKeyboard::new(time), skip = 1);
+		assert_eq!(
+			write(&UngrabKeyboard::new(time)),
+			[32, 0, 0, 2, 0, 0, 0x12, 0x34],
+		);
+	}
+
+	#[test]
+	fn test_change_active_cursor_grab_round_trip() {
+		let change = ChangeActiveCursorGrab::new(
+			CursorEventMask::BUTTON_PRESS,
+			CurrentableTime::CurrentTime,
+		);
+
+		assert_roundtrip!(change, skip = 1);
+		assert_eq!(
+			write(&change),
+			[30, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x04, 0, 0],
+		);
+
+		let change = change.with_cursor_appearance(Some(CursorAppearance::new(0x0020_0001)));
+
+		assert_roundtrip!(change, skip = 1);
+		assert_eq!(write(&change)[4..8], [0x00, 0x20, 0x00, 0x01]);
+	}
+
+	#[test]
+	fn test_allow_events_replay_cursor() {
+		let allow = AllowEvents::now(AllowEventsMode::ReplayCursor);
+
+		assert_roundtrip!(allow, skip = 1);
+		assert_eq!(write(&allow), [35, 2, 0, 2, 0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_allow_events_default() {
+		assert_eq!(AllowEventsMode::default(), AllowEventsMode::UnfreezeCursor);
+		assert_eq!(write(&AllowEvents::default()), [35, 0, 0, 2, 0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_grab_key_round_trip() {
+		let any = GrabKey {
+			owner_events: true,
+			grab_window: Window::new(0x0040_0001),
+			modifiers: AnyModifierKeyMask::ANY_MODIFIER,
+			key: Specificity::Any,
+			cursor_freeze: FreezeMode::Unfrozen,
+			keyboard_freeze: FreezeMode::Unfrozen,
+		};
+
+		assert_roundtrip!(any, skip = 1);
+		assert_eq!(
+			write(&any),
+			[33, 1, 0, 4, 0x00, 0x40, 0x00, 0x01, 0x80, 0x00, 0, 1, 1, 0, 0, 0],
+		);
+
+		let specific = GrabKey {
+			key: Specificity::Other(Keycode::new(38)),
+			..any
+		};
+
+		assert_roundtrip!(specific, skip = 1);
+		assert_eq!(write(&specific)[10], 38);
+	}
+
+	#[test]
+	fn test_set_focus_revert_to_parent() {
+		let request = SetFocus {
+			revert_to: RevertFocus::Parent,
+			new_focus: FocusWindow::Other(Window::new(0x0040_0001)),
+			time: CurrentableTime::CurrentTime,
+		};
+
+		assert_roundtrip!(request, skip = 1);
+		assert_eq!(
+			write(&request),
+			[42, 2, 0, 3, 0x00, 0x40, 0x00, 0x01, 0, 0, 0, 0],
+		);
+	}
+
+	#[test]
+	fn test_ring_bell_new() {
+		let request = RingBell::new(-50).unwrap();
+
+		assert_roundtrip!(request, skip = 1);
+		assert_eq!(write(&request), [104, 0xce, 0, 1]);
+	}
+
+	#[test]
+	fn test_ring_bell_new_out_of_bounds() {
+		assert_eq!(
+			RingBell::new(101),
+			Err(ValueOutOfBounds {
+				min: -100,
+				max: 100,
+				found: 101,
+			}),
+		);
+		assert!(RingBell::new(-101).is_err());
+	}
+
+	#[test]
+	fn test_change_cursor_options_round_trip() {
+		let request = ChangeCursorOptions {
+			acceleration: Fraction::<OrDefault<Px<u8>>>::new(
+				OrDefault::Other(Px(3)),
+				OrDefault::Other(Px(2)),
+			)
+			.unwrap(),
+			threshold: OrDefault::Default,
+
+			do_acceleration: true,
+			do_threshold: false,
+		};
+
+		assert_roundtrip!(request, skip = 1);
+		assert_eq!(
+			write(&request),
+			[105, 0, 0, 3, 0, 3, 0, 2, 0xff, 0xff, 1, 0],
+		);
+	}
+
+	#[test]
+	fn test_get_cursor_options_round_trip() {
+		assert_roundtrip!(GetCursorOptions, skip = 1);
+	}
+
+	#[test]
+	fn test_fraction_ratio() {
+		let acceleration = Fraction::<OrDefault<Px<u8>>>::from_ratio(1.5).unwrap();
+
+		assert_eq!(
+			acceleration.pair(),
+			(&OrDefault::Other(Px(3)), &OrDefault::Other(Px(2)))
+		);
+		assert!(acceleration
+			.as_ratio()
+			.is_some_and(|ratio| (ratio - 1.5).abs() < f32::EPSILON));
+
+		let third = Fraction::<OrDefault<Px<u8>>>::from_ratio(1.0 / 3.0).unwrap();
+		assert_eq!(
+			third.pair(),
+			(&OrDefault::Other(Px(1)), &OrDefault::Other(Px(3)))
+		);
+
+		let default =
+			Fraction::<OrDefault<Px<u8>>>::new(OrDefault::Default, OrDefault::Other(Px(1)))
+				.unwrap();
+		assert_eq!(default.as_ratio(), None);
+
+		assert!(Fraction::<OrDefault<Px<u8>>>::from_ratio(-1.0).is_none());
+		assert!(Fraction::<OrDefault<Px<u8>>>::from_ratio(256.0).is_none());
+		assert!(Fraction::<OrDefault<Px<u8>>>::from_ratio(f32::NAN).is_none());
+
+		let ratio = Fraction::<Px<u16>>::new(Px(5), Px(4)).unwrap().as_ratio();
+		assert!((ratio - 1.25).abs() < f32::EPSILON);
+	}
+
+	#[test]
+	fn test_get_focus_reply_type() {
+		fn reply_type<Req: Request<Reply = reply::GetFocus>>(_request: &Req) {}
+
+		reply_type(&GetFocus);
+	}
+
+	#[test]
+	fn test_get_focus_reply_for_major_opcode() {
+		use crate::message::Reply;
+
+		assert_eq!(
+			<reply::GetFocus as Reply>::FOR_MAJOR_OPCODE,
+			GetFocus::MAJOR_OPCODE
+		);
+		assert_eq!(<reply::GetFocus as Reply>::FOR_MAJOR_OPCODE, 43);
+	}
+
+	#[test]
+	fn test_warp_cursor_relative() {
+		let request = WarpCursor::relative(Px(-3), Px(4));
+
+		assert_eq!(request.source, None);
+		assert_eq!(request.destination, None);
+		assert_eq!(request.coords, Coords::new(Px(-3), Px(4)));
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 24);
+		// `source` and `destination` are both `None`.
+		assert_eq!(bytes[4..12], [0; 8]);
+		assert_eq!(bytes[20..24], [0xff, 0xfd, 0x00, 0x04]);
+
+		assert_eq!(WarpCursor::read_from(&mut &bytes[1..]).unwrap(), request);
+	}
+
+	#[test]
+	fn test_warp_cursor_to_window() {
+		let request = WarpCursor::to_window(Window::new(0x0102_0304), Px(10), Px(20));
+
+		assert_eq!(request.source, None);
+		assert_eq!(request.destination, Some(Window::new(0x0102_0304)));
+		assert_eq!(request.coords, Coords::new(Px(10), Px(20)));
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 24);
+		assert_eq!(bytes[4..8], [0; 4]);
+		assert_eq!(bytes[8..12], [0x01, 0x02, 0x03, 0x04]);
+
+		assert_eq!(WarpCursor::read_from(&mut &bytes[1..]).unwrap(), request);
+	}
+
+	#[test]
+	fn test_grab_and_ungrab_server() {
+		assert_eq!(GrabServer::MAJOR_OPCODE, 36);
+		assert_eq!(UngrabServer::MAJOR_OPCODE, 37);
+
+		// Both requests are just a header.
+		assert_eq!(GrabServer::X11_SIZE, 4);
+		assert_eq!(UngrabServer::X11_SIZE, 4);
+		assert_eq!(GrabServer.length(), 1);
+		assert_eq!(UngrabServer.length(), 1);
+
+		assert_eq!(write(&GrabServer), [36, 0, 0, 1]);
+		assert_eq!(write(&UngrabServer), [37, 0, 0, 1]);
+
+		assert_roundtrip!(GrabServer, skip = 1);
+		assert_roundtrip!(UngrabServer, skip = 1);
+	}
+
+	#[test]
+	fn test_set_button_mapping_round_trip() {
+		let request = SetButtonMapping {
+			mappings: vec![
+				Some(Button::new(3)),
+				None,
+				Some(Button::new(1)),
+				Some(Button::new(4)),
+				Some(Button::new(5)),
+			],
+		};
+
+		assert_eq!(SetButtonMapping::MAJOR_OPCODE, 116);
+		assert_eq!(request.length(), 3);
+		assert_roundtrip!(request, skip = 1);
+
+		assert_eq!(
+			write(&request),
+			[
+				116, 5, 0, 3, // header
+				3, 0, 1, 4, // mappings
+				5, 0, 0, 0, // mappings and padding
+			],
+		);
+	}
+
+	#[test]
+	fn test_set_modifier_mapping_round_trip() {
+		let request = SetModifierMapping {
+			shift_keycodes: vec![Keycode(50), Keycode(62)],
+			capslock_keycodes: vec![Keycode(66)],
+			ctrl_keycodes: vec![Keycode(37), Keycode(105)],
+
+			mod1_keycodes: vec![],
+			mod2_keycodes: vec![],
+			mod3_keycodes: vec![],
+			mod4_keycodes: vec![Keycode(133)],
+			mod5_keycodes: vec![],
+		};
+
+		assert_eq!(request.metabyte().unwrap(), 2);
+		assert_roundtrip!(request, skip = 1);
+
+		assert_eq!(
+			write(&request),
+			[
+				118, 2, 0, 5, // header
+				50, 62, 66, 0, // shift and caps lock
+				37, 105, 0, 0, // control and Mod1
+				0, 0, 0, 0, // Mod2 and Mod3
+				133, 0, 0, 0, // Mod4 and Mod5
+			],
+		);
+	}
+
+	#[test]
+	fn test_get_button_mapping() {
+		assert_eq!(GetButtonMapping::MAJOR_OPCODE, 117);
+		assert_eq!(write(&GetButtonMapping), [117, 0, 0, 1]);
+	}
+}
@@ -25,17 +25,17 @@ use xrbk::{
 	WriteResult,
 	X11Size,
 };
-use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
+use xrbk_macro::{derive_xrb, ConstantX11Size, Readable, Writable, X11Size};
 
 use crate::{
 	message::Request,
 	unit::Sec,
 	x11::{error, reply},
+	Defaultable,
 	Host,
 	KillClientTarget,
 	String8,
 	Toggle,
-	ToggleOrDefault,
 	Window,
 };
 
@@ -79,11 +79,14 @@ request_error! {
 }
 
 /// Whether something is added or removed.
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[doc(alias("SetMode", "EditMode"))]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, ConstantX11Size, Readable, Writable)]
 pub enum AddOrRemove {
 	/// The thing is added.
+	#[doc(alias = "Insert")]
 	Add,
 	/// The thing is removed.
+	#[doc(alias = "Delete")]
 	Remove,
 }
 
@@ -240,25 +243,25 @@ derive_xrb! {
 	/// seconds without any cursor or keyboard input, the screensaver is
 	/// activated.
 	///
-	/// If [`prefer_blanking`] is [`Enabled`], displays that support blanking
-	/// will go blank when the screensaver is activated.
+	/// If [`prefer_blanking`] is `true`, displays that support blanking will
+	/// go blank when the screensaver is activated.
 	///
-	/// Otherwise, if [`prefer_blanking`] is [`Disabled`] or the display does
-	/// not support blanking and either [`allow_expose_events`] is [`Enabled`]
-	/// or the [screen] can be changed without generating [`Expose` events], the
+	/// Otherwise, if [`prefer_blanking`] is `false` or the display does not
+	/// support blanking and either [`allow_expose_events`] is `true` or the
+	/// [screen] can be changed without generating [`Expose` events], the
 	/// [screen] is changed with a server-specific screensaver.
 	///
-	/// Otherwise, if [`prefer_blanking`] is [`Disabled`], the display does
-	/// not support blanking, or [`allow_expose_events`] is [`Disabled`] and the
+	/// Otherwise, if [`prefer_blanking`] is `false`, the display does not
+	/// support blanking, or [`allow_expose_events`] is `false` and the
 	/// [screen] cannot be changed without generating [`Expose` events], no
 	/// screensaver is activated.
 	///
+	/// A `SetScreenSaver` request may also be created from
+	/// [`ScreenSaverSettings`].
+	///
 	/// [screen]: crate::visual::Screen
 	/// [request]: Request
 	///
-	/// [`Enabled`]: ToggleOrDefault::Enabled
-	/// [`Disabled`]: ToggleOrDefault::Disabled
-	///
 	/// [`timeout`]: SetScreenSaver::timeout
 	/// [`prefer_blanking`]: SetScreenSaver::prefer_blanking
 	/// [`allow_expose_events`]: SetScreenSaver::allow_expose_events
@@ -280,11 +283,11 @@ derive_xrb! {
 
 		/// Whether it is preferred that displays that support blanking go blank
 		/// when the screensaver is activated.
-		pub prefer_blanking: ToggleOrDefault,
+		pub prefer_blanking: Defaultable<bool>,
 		/// Whether screensavers which generate [`Expose` events] are allowed.
 		///
 		/// [`Expose` events]: crate::x11::event::Expose
-		pub allow_expose_events: ToggleOrDefault,
+		pub allow_expose_events: Defaultable<bool>,
 		[_; 2],
 	}
 
@@ -303,6 +306,72 @@ derive_xrb! {
 	pub struct GetScreenSaver: Request(108) -> reply::GetScreenSaver;
 }
 
+impl Default for SetScreenSaver {
+	/// Creates a `SetScreenSaver` [request] which restores the default value of
+	/// every screensaver option.
+	///
+	/// [request]: Request
+	fn default() -> Self {
+		ScreenSaverSettings::default().into()
+	}
+}
+
+/// The options configured by a [`SetScreenSaver` request].
+///
+/// See [`SetScreenSaver`] for what each option means.
+///
+/// [`SetScreenSaver` request]: SetScreenSaver
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct ScreenSaverSettings {
+	/// See [`SetScreenSaver::timeout`].
+	pub timeout: Delay,
+	/// See [`SetScreenSaver::interval`].
+	pub interval: Delay,
+
+	/// See [`SetScreenSaver::prefer_blanking`].
+	pub prefer_blanking: Defaultable<bool>,
+	/// See [`SetScreenSaver::allow_expose_events`].
+	pub allow_expose_events: Defaultable<bool>,
+}
+
+impl Default for ScreenSaverSettings {
+	/// Creates `ScreenSaverSettings` which restore the default value of every
+	/// screensaver option.
+	fn default() -> Self {
+		Self {
+			timeout: Delay::Default,
+			interval: Delay::Default,
+
+			prefer_blanking: Defaultable::Default,
+			allow_expose_events: Defaultable::Default,
+		}
+	}
+}
+
+impl From<ScreenSaverSettings> for SetScreenSaver {
+	fn from(settings: ScreenSaverSettings) -> Self {
+		Self {
+			timeout: settings.timeout,
+			interval: settings.interval,
+
+			prefer_blanking: settings.prefer_blanking,
+			allow_expose_events: settings.allow_expose_events,
+		}
+	}
+}
+
+impl From<SetScreenSaver> for ScreenSaverSettings {
+	fn from(request: SetScreenSaver) -> Self {
+		Self {
+			timeout: request.timeout,
+			interval: request.interval,
+
+			prefer_blanking: request.prefer_blanking,
+			allow_expose_events: request.allow_expose_events,
+		}
+	}
+}
+
 request_error! {
 	pub enum ChangeHostsError for ChangeHosts {
 		Access,
@@ -411,6 +480,30 @@ derive_xrb! {
 	}
 }
 
+#[allow(
+	deprecated,
+	reason = "`SetAccessControl` is still part of the protocol"
+)]
+impl SetAccessControl {
+	/// Creates a new `SetAccessControl` [request] which [enables] access
+	/// control if `enabled` is `true` and [disables] it otherwise.
+	///
+	/// [request]: Request
+	///
+	/// [enables]: Toggle::Enabled
+	/// [disables]: Toggle::Disabled
+	#[must_use]
+	pub const fn new(enabled: bool) -> Self {
+		Self {
+			mode: if enabled {
+				Toggle::Enabled
+			} else {
+				Toggle::Disabled
+			},
+		}
+	}
+}
+
 /// Defines what happens to a client's resources when its connection ends.
 ///
 /// The default mode (i.e. the mode set when a connection is first set up)
@@ -418,7 +511,9 @@ derive_xrb! {
 ///
 /// [`Destroy`]: RetainResourcesMode::Destroy
 #[doc(alias("CloseDownMode"))]
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[derive(
+	Copy, Clone, Default, Debug, Hash, PartialEq, Eq, X11Size, ConstantX11Size, Readable, Writable,
+)]
 pub enum RetainResourcesMode {
 	/// All of the client's resources are destroyed immediately.
 	///
@@ -437,6 +532,7 @@ pub enum RetainResourcesMode {
 	/// [chosen to be saved]: ChangeSavedWindows
 	///
 	/// [`ChangeSavedWindows` request]: ChangeSavedWindows
+	#[default]
 	Destroy,
 
 	/// All of the client's resources are marked as permanently retained.
@@ -508,7 +604,7 @@ pub enum ForceScreenSaverMode {
 	/// the time left before its activation) is reset and, if the screensaver is
 	/// active, the screensaver is deactivated.
 	///
-	/// [enabled]: ToggleOrDefault::Enabled
+	/// [enabled]: Delay::Enabled
 	Reset,
 
 	/// If the screensaver is not currently active, it is forcibly activated.
@@ -516,7 +612,7 @@ pub enum ForceScreenSaverMode {
 	/// The screensaver is activated even if [`timeout`] is [`Disabled`].
 	///
 	/// [`timeout`]: SetScreenSaver::timeout
-	/// [`Disabled`]: ToggleOrDefault::Disabled
+	/// [`Disabled`]: Delay::Disabled
 	Activate,
 }
 
@@ -614,3 +710,156 @@ impl Writable for NoOp {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use std::net::{IpAddr, Ipv4Addr};
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+
+	#[test]
+	fn test_change_saved_windows_round_trip() {
+		for (change_mode, metabyte) in [(AddOrRemove::Add, 0), (AddOrRemove::Remove, 1)] {
+			let request = ChangeSavedWindows {
+				change_mode,
+				window: Window::new(0x0040_0001),
+			};
+
+			assert_eq!(ChangeSavedWindows::MAJOR_OPCODE, 6);
+			assert_eq!(ChangeSavedWindows::X11_SIZE, 8);
+			assert_roundtrip!(request, skip = 1);
+
+			let bytes = write(&request);
+
+			assert_eq!(bytes, [6, metabyte, 0, 2, 0x00, 0x40, 0x00, 0x01]);
+		}
+	}
+
+	#[test]
+	#[allow(
+		deprecated,
+		reason = "`SetAccessControl` is still part of the protocol"
+	)]
+	fn test_set_access_control_round_trip() {
+		for (enabled, metabyte) in [(false, 0), (true, 1)] {
+			let request = SetAccessControl::new(enabled);
+
+			assert_eq!(SetAccessControl::MAJOR_OPCODE, 111);
+			assert_eq!(bool::from(request.mode), enabled);
+			assert_roundtrip!(request, skip = 1);
+
+			let bytes = write(&request);
+
+			assert_eq!(bytes, [111, metabyte, 0, 1]);
+		}
+	}
+
+	#[test]
+	fn test_set_retain_resources_mode_round_trip() {
+		assert_eq!(RetainResourcesMode::default(), RetainResourcesMode::Destroy);
+
+		for (mode, metabyte) in [
+			(RetainResourcesMode::Destroy, 0),
+			(RetainResourcesMode::RetainPermanently, 1),
+			(RetainResourcesMode::RetainTemporarily, 2),
+		] {
+			let request = SetRetainResourcesMode { mode };
+
+			assert_eq!(SetRetainResourcesMode::MAJOR_OPCODE, 112);
+			assert_roundtrip!(request, skip = 1);
+
+			let bytes = write(&request);
+
+			assert_eq!(bytes, [112, metabyte, 0, 1]);
+		}
+	}
+
+	#[test]
+	#[allow(deprecated, reason = "`ChangeHosts` is still part of the protocol")]
+	fn test_change_hosts_round_trip() {
+		let request = ChangeHosts {
+			mode: AddOrRemove::Add,
+			host: Host::from(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [109, 0, 0, 3, 0, 0, 0, 4, 10, 0, 0, 1]);
+	}
+
+	#[test]
+	fn test_set_screen_saver_round_trip() {
+		let request = SetScreenSaver {
+			timeout: Delay::Enabled(Sec(120)),
+			interval: Delay::Disabled,
+
+			prefer_blanking: Defaultable::Other(true),
+			allow_expose_events: Defaultable::Other(false),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [107, 0, 0, 3, 0, 120, 0, 0, 1, 0, 0, 0]);
+	}
+
+	#[test]
+	fn test_set_screen_saver_default() {
+		let request = SetScreenSaver::default();
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [107, 0, 0, 3, 0xff, 0xff, 0xff, 0xff, 2, 2, 0, 0]);
+	}
+
+	#[test]
+	fn test_screen_saver_settings() {
+		let request = SetScreenSaver::from(ScreenSaverSettings {
+			timeout: Delay::Enabled(Sec(60)),
+			interval: Delay::Default,
+
+			prefer_blanking: Defaultable::Default,
+			allow_expose_events: Defaultable::Other(true),
+		});
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [107, 0, 0, 3, 0, 60, 0xff, 0xff, 2, 1, 0, 0]);
+		assert_eq!(
+			ScreenSaverSettings::from(request),
+			ScreenSaverSettings {
+				timeout: Delay::Enabled(Sec(60)),
+				interval: Delay::Default,
+
+				prefer_blanking: Defaultable::Default,
+				allow_expose_events: Defaultable::Other(true),
+			},
+		);
+	}
+
+	#[test]
+	fn test_get_screen_saver_round_trip() {
+		assert_roundtrip!(GetScreenSaver, skip = 1);
+	}
+
+	#[test]
+	fn test_force_screen_saver_round_trip() {
+		let request = ForceScreenSaver {
+			mode: ForceScreenSaverMode::Activate,
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [115, 1, 0, 1]);
+	}
+}
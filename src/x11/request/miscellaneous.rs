@@ -33,11 +33,12 @@ use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
 use crate::{
 	message::{Event, Request},
 	x11::{error, reply},
-	Any,
 	Atom,
 	CurrentableTime,
 	DestinationWindow,
 	EventMask,
+	NonZeroAtom,
+	Specificity,
 	String8,
 	Window,
 };
@@ -151,7 +152,7 @@ derive_xrb! {
 		///
 		/// [`Atom` error]: error::Atom
 		#[doc(alias = "atom")]
-		pub target: Atom,
+		pub target: NonZeroAtom,
 	}
 }
 
@@ -382,7 +383,7 @@ derive_xrb! {
 		/// [atom]: Atom
 		///
 		/// [`Atom` error]: error::Atom
-		pub property: Atom,
+		pub property: NonZeroAtom,
 		/// The type of the property's data.
 		///
 		/// For example, if the property is of type [`Window`], then this would
@@ -396,7 +397,7 @@ derive_xrb! {
 		/// [`atom::WINDOW`]: crate::atom::WINDOW
 		///
 		/// [`Atom` error]: error::Atom
-		pub r#type: Atom,
+		pub r#type: NonZeroAtom,
 
 		// Whether the `data` is formatted as `i8` values, `i16` values, or
 		// `i32` values.
@@ -473,7 +474,7 @@ derive_xrb! {
 		/// [window]: Window
 		///
 		/// [`Atom` error]: error::Atom
-		pub property: Atom,
+		pub property: NonZeroAtom,
 	}
 }
 
@@ -517,7 +518,7 @@ derive_xrb! {
 		///
 		/// [window]: Window
 		///
-		/// [`Any`]: Any::Any
+		/// [`Any`]: Specificity::Any
 		#[metabyte]
 		pub delete: bool,
 
@@ -541,7 +542,7 @@ derive_xrb! {
 		///
 		/// [atom]: Atom
 		/// [request]: Request
-		pub property: Atom,
+		pub property: NonZeroAtom,
 		/// The property type to filter the [window]'s properties by.
 		///
 		/// This specifies that specifically a `property` of this type is
@@ -551,7 +552,7 @@ derive_xrb! {
 		/// [window]: Window
 		///
 		/// [the reply]: reply::GetProperty
-		pub r#type: Any<Atom>,
+		pub r#type: Specificity<Atom>,
 
 		/// The offset of the value of the `property` that is requested in
 		/// 4-byte units.
@@ -663,7 +664,7 @@ derive_xrb! {
 		/// [request]: Request
 		///
 		/// [`Atom` error]: error::Atom
-		pub selection: Atom,
+		pub selection: NonZeroAtom,
 
 		/// The [time] at which this change is recorded to occur at.
 		///
@@ -704,7 +705,7 @@ derive_xrb! {
 		/// [request]: Request
 		///
 		/// [`Atom` error]: error::Atom
-		pub target: Atom,
+		pub target: NonZeroAtom,
 	}
 }
 
@@ -755,7 +756,7 @@ derive_xrb! {
 		/// [request]: Request
 		///
 		/// [`Atom` error]: error::Atom
-		pub selection: Atom,
+		pub selection: NonZeroAtom,
 
 		/// The type which the selection should be converted into.
 		///
@@ -766,7 +767,21 @@ derive_xrb! {
 		/// [atom]: Atom
 		///
 		/// [`Atom` error]: error::Atom
-		pub target_type: Atom,
+		#[doc(alias = "target")]
+		pub target_type: NonZeroAtom,
+		/// The property on the `requester` which the converted selection
+		/// should be stored in.
+		///
+		/// The [ICCCM] specifies that this should not be [`None`].
+		///
+		/// # Errors
+		/// An [`Atom` error] is generated if this does not refer to a defined
+		/// [atom].
+		///
+		/// [atom]: Atom
+		/// [ICCCM]: https://x.org/releases/X11R7.7/doc/xorg-docs/icccm/icccm.html#Requesting_a_Selection
+		///
+		/// [`Atom` error]: error::Atom
 		pub property: Option<Atom>,
 
 		/// The [time] at which this conversion is recorded as having taken
@@ -914,7 +929,8 @@ derive_xrb! {
 	/// [`Window` error]: error::Window
 	/// [`Atom` error]: error::Atom
 	/// [`Match` error]: error::Match
-	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable, ConstantX11Size)]
+	#[doc(alias("CycleProperties"))]
+	#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
 	pub struct RotateProperties: Request(114, RotatePropertiesError) {
 		/// The [window] for which the given `properties` are rotated.
 		///
@@ -957,6 +973,132 @@ derive_xrb! {
 		/// [`Atom` error]: error::Atom
 		/// [`Match` error]: error::Match
 		#[context(properties_len => usize::from(*properties_len))]
-		pub properties: Vec<Atom>,
+		pub properties: Vec<NonZeroAtom>,
+	}
+}
+
+impl RotateProperties {
+	/// Creates a `RotateProperties` [request] which rotates the given
+	/// `properties` of the `target` [window] by the given `shift`.
+	///
+	/// [request]: Request
+	/// [window]: Window
+	#[must_use]
+	pub fn rotate(target: Window, properties: &[NonZeroAtom], shift: i16) -> Self {
+		Self {
+			target,
+			shift,
+			properties: properties.to_vec(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use xrbk::{assert_roundtrip, test_support::write};
+
+	use super::*;
+	use crate::{
+		atom::{PRIMARY, STRING},
+		Timestamp,
+	};
+
+	#[test]
+	fn test_get_atom_no_creation_round_trip() {
+		let name = b"WM_DELETE_WINDOW";
+		let request = GetAtom {
+			no_creation: true,
+			name: String8::try_from("WM_DELETE_WINDOW").unwrap(),
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		// 8 byte header and a 16-byte name, which needs no padding.
+		assert_eq!(bytes.len(), 24);
+		assert_eq!(bytes[..8], [16, 1, 0, 6, 0, 16, 0, 0]);
+		assert_eq!(bytes[8..], name[..]);
+	}
+
+	#[test]
+	fn test_convert_selection_round_trip() {
+		let request = ConvertSelection {
+			requester: Window::new(0x0040_0001),
+			selection: NonZeroAtom::try_from(PRIMARY).unwrap(),
+			target_type: NonZeroAtom::try_from(STRING).unwrap(),
+			property: Some(Atom::new(300)),
+			time: CurrentableTime::CurrentTime,
+		};
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes.len(), 24);
+		assert_eq!(request.wire_size(), bytes.len());
+		assert_eq!(bytes[..8], [24, 0, 0, 6, 0x00, 0x40, 0x00, 0x01]);
+		assert_eq!(
+			bytes[8..],
+			[0, 0, 0, 1, 0, 0, 0, 31, 0, 0, 0x01, 0x2c, 0, 0, 0, 0]
+		);
+	}
+
+	#[test]
+	fn test_rotate_properties() {
+		let request = RotateProperties::rotate(
+			Window::new(0x0040_0001),
+			&[39, 37, 300].map(|id| Atom::new_nonzero(id).unwrap()),
+			-1,
+		);
+
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		// 12 byte header and three 4-byte atoms.
+		assert_eq!(bytes.len(), 24);
+		assert_eq!(bytes[..4], [114, 0, 0, 6]);
+		// The number of properties, then the shift.
+		assert_eq!(bytes[8..12], [0, 3, 0xff, 0xff]);
+	}
+
+	#[test]
+	fn test_set_selection_owner_round_trip() {
+		for (new_owner, owner_bytes) in [
+			(None, [0, 0, 0, 0]),
+			(Some(Window::new(0x0040_0001)), [0x00, 0x40, 0x00, 0x01]),
+		] {
+			let request = SetSelectionOwner {
+				new_owner,
+				selection: NonZeroAtom::try_from(PRIMARY).unwrap(),
+				time: CurrentableTime::Other(Timestamp::new(0x0102_0304)),
+			};
+
+			assert_eq!(SetSelectionOwner::MAJOR_OPCODE, 22);
+			assert_roundtrip!(request, skip = 1);
+
+			let bytes = write(&request);
+
+			assert_eq!(bytes.len(), 16);
+			assert_eq!(bytes[..4], [22, 0, 0, 4]);
+			assert_eq!(bytes[4..8], owner_bytes);
+			assert_eq!(bytes[8..12], [0, 0, 0, 1]);
+			assert_eq!(bytes[12..], [0x01, 0x02, 0x03, 0x04]);
+		}
+	}
+
+	#[test]
+	fn test_get_selection_owner_round_trip() {
+		let request = GetSelectionOwner {
+			target: NonZeroAtom::try_from(PRIMARY).unwrap(),
+		};
+
+		assert_eq!(GetSelectionOwner::MAJOR_OPCODE, 23);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(bytes, [23, 0, 0, 2, 0, 0, 0, 1]);
 	}
 }
@@ -13,7 +13,7 @@
 
 extern crate self as xrb;
 
-use xrbk_macro::{derive_xrb, Readable, Writable, X11Size};
+use xrbk_macro::{derive_xrb, ConstantX11Size, Readable, Writable, X11Size};
 
 use crate::{
 	message::Request,
@@ -660,7 +660,7 @@ request_error! {
 /// [window]: Window
 ///
 /// [`CirculateWindow` request]: CirculateWindow
-#[derive(Debug, Hash, PartialEq, Eq, X11Size, Readable, Writable)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, X11Size, ConstantX11Size, Readable, Writable)]
 pub enum CirculateDirection {
 	/// Raises the lowest mapped child that is occluded by another child, if
 	/// any, to the top of the stack.
@@ -794,3 +794,103 @@ derive_xrb! {
 		pub target: Window,
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use std::fmt::Debug;
+
+	use xrbk::{assert_roundtrip, test_support::write, ConstantX11Size, Readable, Writable};
+
+	use super::*;
+
+	const TARGET: Window = Window::new(0x0040_0001);
+
+	/// Asserts that a [request] taking only a `target` [window] round-trips
+	/// and is written as its `opcode` followed by the `target`.
+	///
+	/// [request]: Request
+	/// [window]: Window
+	#[track_caller]
+	fn assert_window_request<R>(request: &R, opcode: u8)
+	where
+		R: Request + ConstantX11Size + Readable + Writable + PartialEq + Debug,
+	{
+		assert_eq!(R::MAJOR_OPCODE, opcode);
+		assert_eq!(R::X11_SIZE, 8);
+		assert_roundtrip!(*request, skip = 1);
+
+		let bytes = write(request);
+
+		assert_eq!(bytes, [opcode, 0, 0, 2, 0x00, 0x40, 0x00, 0x01]);
+	}
+
+	#[test]
+	fn test_reparent_window_round_trip() {
+		let request = ReparentWindow {
+			target: TARGET,
+			new_parent: Window::new(0x0000_0100),
+			coords: Coords::new(Px(-5), Px(20)),
+		};
+
+		assert_eq!(ReparentWindow::MAJOR_OPCODE, 7);
+		assert_eq!(ReparentWindow::X11_SIZE, 16);
+		assert_roundtrip!(request, skip = 1);
+
+		let bytes = write(&request);
+
+		assert_eq!(
+			bytes,
+			[
+				7, 0, 0, 4, // header
+				0x00, 0x40, 0x00, 0x01, // target
+				0x00, 0x00, 0x01, 0x00, // new_parent
+				0xff, 0xfb, 0x00, 0x14, // coords
+			],
+		);
+	}
+
+	#[test]
+	fn test_circulate_window_round_trip() {
+		for (direction, metabyte) in [
+			(CirculateDirection::RaiseLowest, 0),
+			(CirculateDirection::LowerHighest, 1),
+		] {
+			let request = CirculateWindow {
+				direction,
+				target: TARGET,
+			};
+
+			assert_eq!(CirculateWindow::MAJOR_OPCODE, 13);
+			assert_eq!(CirculateWindow::X11_SIZE, 8);
+			assert_roundtrip!(request, skip = 1);
+
+			let bytes = write(&request);
+
+			assert_eq!(bytes, [13, metabyte, 0, 2, 0x00, 0x40, 0x00, 0x01]);
+		}
+	}
+
+	#[test]
+	fn test_single_window_requests_round_trip() {
+		assert_window_request(&DestroyWindow { target: TARGET }, 4);
+		assert_window_request(&DestroyChildren { target: TARGET }, 5);
+		assert_window_request(&MapWindow { target: TARGET }, 8);
+		assert_window_request(&MapChildren { target: TARGET }, 9);
+		assert_window_request(&UnmapWindow { target: TARGET }, 10);
+		assert_window_request(&UnmapChildren { target: TARGET }, 11);
+	}
+
+	#[test]
+	fn test_opcodes_in_const_context() {
+		// The opcodes are associated consts so that they may be used in
+		// `const` contexts, such as in dispatch tables.
+		const MAP_WINDOW: u8 = MapWindow::MAJOR_OPCODE;
+		const DISPATCH: [(u8, Option<u16>); 2] = [
+			(MapWindow::MAJOR_OPCODE, MapWindow::MINOR_OPCODE),
+			(UnmapWindow::MAJOR_OPCODE, UnmapWindow::MINOR_OPCODE),
+		];
+
+		assert_eq!(MAP_WINDOW, 8);
+		assert_eq!(DISPATCH, [(8, None), (10, None)]);
+	}
+}
@@ -34,6 +34,7 @@ use std::{
 };
 
 pub use bytes::{Buf, BufMut};
+pub use tracked::TrackedReader;
 
 use thiserror::Error;
 
@@ -43,7 +44,7 @@ use thiserror::Error;
 /// This is frequently used in X11 because all messages must have a total length
 /// that is a multiple of four bytes. This function can determine how many
 /// unused bytes need to be added to ensure that.
-pub fn pad<T: X11Size>(value: &T) -> usize {
+pub fn pad<T: X11Size + ?Sized>(value: &T) -> usize {
 	const ALIGNMENT: usize = 4;
 
 	let x11_size = value.x11_size();
@@ -51,6 +52,42 @@ pub fn pad<T: X11Size>(value: &T) -> usize {
 	(ALIGNMENT - (x11_size % ALIGNMENT)) % ALIGNMENT
 }
 
+/// Reads the next `len` bytes from `buf` in a single copy.
+///
+/// This is equivalent to reading `len` `u8`s one at a time, as the
+/// [`ReadableWithContext`] implementation for `Vec<u8>` would, but much
+/// faster for large amounts of data, such as images.
+///
+/// # Errors
+/// Returns [`ReadError::UnexpectedEnd`] if `buf` has fewer than `len` bytes
+/// remaining. This is checked before anything is allocated, so a `len` read
+/// from the wire cannot cause a large allocation on its own.
+pub fn read_bytes(buf: &mut impl Buf, len: usize) -> ReadResult<Vec<u8>> {
+	let remaining = buf.remaining();
+
+	if remaining < len {
+		return Err(ReadError::UnexpectedEnd {
+			expected: len,
+			remaining,
+		});
+	}
+
+	let mut bytes = vec![0; len];
+	buf.copy_to_slice(&mut bytes);
+
+	Ok(bytes)
+}
+
+/// Reads all of the bytes remaining in `buf` in a single copy.
+///
+/// See [`read_bytes`].
+pub fn read_remaining(buf: &mut impl Buf) -> Vec<u8> {
+	let mut bytes = vec![0; buf.remaining()];
+	buf.copy_to_slice(&mut bytes);
+
+	bytes
+}
+
 pub type ReadResult<T> = Result<T, ReadError>;
 pub type WriteResult = Result<(), WriteError>;
 
@@ -67,6 +104,23 @@ pub enum ReadError {
 	FailedConversion(Box<dyn Any>),
 	#[error("{0}")]
 	Other(Box<dyn DebugDisplay>),
+
+	#[error("expected {expected} bytes, but only {remaining} remain")]
+	UnexpectedEnd { expected: usize, remaining: usize },
+	#[error("expected a reply (message type 1), found message type {found}")]
+	UnexpectedReplyType { found: u8 },
+	#[error("failed at offset {offset} reading field `{field}`: {source}")]
+	AtOffset {
+		offset: usize,
+		field: &'static str,
+		source: Box<Self>,
+	},
+	#[error("field `{field}` must begin at offset {offset}, but {size} bytes precede it")]
+	MisplacedField {
+		field: &'static str,
+		offset: usize,
+		size: usize,
+	},
 }
 
 #[non_exhaustive]
@@ -76,13 +130,25 @@ pub enum WriteError {
 	FailedConversion(Box<dyn Any>),
 	#[error("{0}")]
 	Other(Box<dyn DebugDisplay>),
+
+	#[error("field `{field}` must begin at offset {offset}, but {size} bytes precede it")]
+	MisplacedField {
+		field: &'static str,
+		offset: usize,
+		size: usize,
+	},
 }
 
 mod readable;
+mod tracked;
 mod wrap;
 mod writable;
 mod x11_size;
 
+#[cfg(any(test, feature = "testing"))]
+#[doc(hidden)]
+pub mod test_support;
+
 /// Gives the type size in bytes.
 /// The size can vary depending on the quantity of data it contains
 pub trait X11Size {
@@ -193,7 +259,7 @@ where
 	where
 		Self: Sized,
 	{
-		Ok(match <T::Integer>::read_from(buf).unwrap() {
+		Ok(match <T::Integer>::read_from(buf)? {
 			discrim if discrim.into() == 0_u64 => None,
 			value => Some(match T::try_from(value) {
 				Ok(value) => value,
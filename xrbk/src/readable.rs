@@ -4,7 +4,7 @@
 
 //! [`Readable`] implementations for primitive types
 
-use crate::{ReadResult, Readable, ReadableWithContext, X11Size};
+use crate::{ReadError, ReadResult, Readable, ReadableWithContext, X11Size};
 use bytes::Buf;
 use std::ops::{Range, RangeInclusive};
 
@@ -35,8 +35,41 @@ implement! {
 
 	reader, f32 => reader.get_f32(),
 	reader, f64 => reader.get_f64(),
+}
+
+/// Reads a `bool` strictly: only `0` (`false`) and `1` (`true`) are valid.
+///
+/// Any other byte is rejected with [`ReadError::Other`] so that corrupt
+/// messages are detected rather than silently read as `true`.
+impl Readable for bool {
+	fn read_from(reader: &mut impl Buf) -> ReadResult<Self> {
+		match reader.get_u8() {
+			0 => Ok(false),
+			1 => Ok(true),
 
-	reader, bool => reader.get_u8() != 0,
+			other => Err(ReadError::Other(Box::new(format!(
+				"expected a bool (0 or 1), found {other}"
+			)))),
+		}
+	}
+}
+
+/// Reads a single unused byte, as written for a `_` element.
+///
+/// The byte's value is ignored.
+impl Readable for () {
+	fn read_from(reader: &mut impl Buf) -> ReadResult<Self> {
+		if !reader.has_remaining() {
+			return Err(ReadError::UnexpectedEnd {
+				expected: 1,
+				remaining: 0,
+			});
+		}
+
+		reader.advance(1);
+
+		Ok(())
+	}
 }
 
 impl<T: Readable, const N: usize> Readable for [T; N] {
@@ -82,6 +115,55 @@ impl<T: Readable> ReadableWithContext for Vec<T> {
 	}
 }
 
+/// Reads a grid of `rows` lists, each containing `columns` elements.
+///
+/// The context is `(rows, columns)`.
+impl<T: Readable> ReadableWithContext for Vec<Vec<T>> {
+	type Context = (usize, usize);
+
+	fn read_with(reader: &mut impl Buf, (rows, columns): &(usize, usize)) -> ReadResult<Self>
+	where
+		Self: Sized,
+	{
+		let mut grid = Self::with_capacity(*rows);
+
+		for _ in 0..*rows {
+			grid.push(<Vec<T>>::read_with(reader, columns)?);
+		}
+
+		Ok(grid)
+	}
+}
+
+macro_rules! implement_tuple {
+	($(($($T:ident $context:ident),+)),*$(,)?) => {
+		$(
+			/// Reads each element in order, each with its own context.
+			///
+			/// The context is a tuple of each element's context.
+			impl<$($T: ReadableWithContext),+> ReadableWithContext for ($($T,)+) {
+				type Context = ($($T::Context,)+);
+
+				fn read_with(
+					reader: &mut impl Buf,
+					($($context,)+): &Self::Context,
+				) -> ReadResult<Self>
+				where
+					Self: Sized,
+				{
+					Ok(($($T::read_with(reader, $context)?,)+))
+				}
+			}
+		)*
+	};
+}
+
+implement_tuple! {
+	(A a, B b),
+	(A a, B b, C c),
+	(A a, B b, C c, D d),
+}
+
 impl<T: X11Size + Clone> ReadableWithContext for Range<T> {
 	type Context = (T, T);
 
@@ -106,3 +188,105 @@ impl<T: X11Size + Clone> ReadableWithContext for RangeInclusive<T> {
 		Ok(Self::new(start.clone(), end.clone()))
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_grid_read_with_context() {
+		// Three rows of two `u16`s.
+		let bytes = [0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6];
+		let (rows, columns): (u8, u8) = (3, 2);
+
+		let grid =
+			<Vec<Vec<u16>>>::read_with(&mut &bytes[..], &(usize::from(rows), usize::from(columns)))
+				.unwrap();
+
+		assert_eq!(grid, [vec![1, 2], vec![3, 4], vec![5, 6]]);
+	}
+
+	#[test]
+	fn test_tuple_read_with_context() {
+		let bytes = [1, 2, 0, 3, 0, 4, 0, 5];
+
+		let (first, second) = <(Vec<u8>, Vec<u16>)>::read_with(&mut &bytes[..], &(2, 3)).unwrap();
+
+		assert_eq!(first, [1, 2]);
+		assert_eq!(second, [3, 4, 5]);
+	}
+
+	#[test]
+	fn test_read_remaining() {
+		let bytes: Vec<u8> = (0..104).collect();
+		let buf = &mut &bytes[..];
+
+		// Read a 4-byte header, then the 100-byte tail in one go.
+		assert_eq!(u32::read_from(buf).unwrap(), 0x0001_0203);
+
+		let tail = crate::read_remaining(buf);
+
+		assert_eq!(tail.len(), 100);
+		assert_eq!(tail, bytes[4..]);
+		assert!(!buf.has_remaining());
+
+		assert_eq!(crate::read_bytes(&mut &bytes[..], 3).unwrap(), [0, 1, 2]);
+	}
+
+	#[test]
+	fn test_read_bytes_truncated() {
+		let buf = &mut &[0, 1, 2][..];
+
+		assert!(matches!(
+			crate::read_bytes(buf, 4),
+			Err(ReadError::UnexpectedEnd {
+				expected: 4,
+				remaining: 3,
+			}),
+		));
+		// Nothing is consumed if there are too few bytes.
+		assert_eq!(buf.remaining(), 3);
+
+		// A huge length from the wire is rejected without being allocated.
+		assert!(matches!(
+			crate::read_bytes(buf, usize::MAX),
+			Err(ReadError::UnexpectedEnd { .. }),
+		));
+	}
+
+	#[test]
+	fn test_unit_read() {
+		let buf = &mut &[0xff, 1][..];
+
+		// Any value is accepted for an unused byte.
+		<()>::read_from(buf).unwrap();
+		assert_eq!(buf.remaining(), 1);
+
+		<()>::read_from(buf).unwrap();
+		assert!(matches!(
+			<()>::read_from(buf),
+			Err(ReadError::UnexpectedEnd {
+				expected: 1,
+				remaining: 0,
+			}),
+		));
+	}
+
+	#[test]
+	fn test_bool_read() {
+		assert!(!bool::read_from(&mut &[0][..]).unwrap());
+		assert!(bool::read_from(&mut &[1][..]).unwrap());
+	}
+
+	#[test]
+	fn test_bool_read_rejects_non_boolean() {
+		assert!(matches!(
+			bool::read_from(&mut &[2][..]),
+			Err(ReadError::Other(_)),
+		));
+		assert!(matches!(
+			bool::read_from(&mut &[0xff][..]),
+			Err(ReadError::Other(_)),
+		));
+	}
+}
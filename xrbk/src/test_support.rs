@@ -0,0 +1,92 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Utilities for testing the (de)serialization of types.
+//!
+//! This is only available with the `testing` feature.
+
+use std::fmt::Debug;
+
+use crate::{Readable, Writable};
+
+/// Asserts that a value is written and then read back unchanged.
+///
+/// The value is written with [`Writable`], then it is asserted that the number
+/// of bytes written is equal to its [`x11_size`]. The bytes are then read back
+/// with [`Readable`], and the result is asserted to be equal to the original
+/// value, with no bytes left unread.
+///
+/// Messages do not read their leading byte(s) themselves - for example, an
+/// [event]'s [`Readable`] implementation expects its code to have already been
+/// read. The number of leading bytes to skip before reading may be given with
+/// `skip`:
+/// ```ignore
+/// // Plain types read every byte they write.
+/// assert_roundtrip!(Coords::new(Px(1), Px(2)));
+///
+/// // Events expect their code to have been read already.
+/// assert_roundtrip!(event, skip = 1);
+/// ```
+///
+/// [`x11_size`]: crate::X11Size::x11_size
+/// [event]: https://docs.aquariwm.org/doc/xrb/message/trait.Event.html
+#[macro_export]
+macro_rules! assert_roundtrip {
+	($value:expr $(,)?) => {
+		$crate::test_support::assert_roundtrip(&$value, 0)
+	};
+
+	($value:expr, skip = $skip:expr $(,)?) => {
+		$crate::test_support::assert_roundtrip(&$value, $skip)
+	};
+}
+
+/// Writes the given `value` to a new buffer and returns the bytes written.
+///
+/// # Panics
+/// Panics if writing the `value` fails.
+#[track_caller]
+pub fn write(value: &(impl Writable + ?Sized)) -> Vec<u8> {
+	let mut bytes = vec![];
+	value.write_to(&mut bytes).expect("failed to write value");
+
+	bytes
+}
+
+/// The implementation of [`assert_roundtrip!`].
+///
+/// [`assert_roundtrip!`]: crate::assert_roundtrip
+#[track_caller]
+pub fn assert_roundtrip<T>(value: &T, skip: usize)
+where
+	T: Readable + Writable + PartialEq + Debug,
+{
+	let mut bytes = vec![];
+	value.write_to(&mut bytes).expect("failed to write value");
+
+	assert_eq!(
+		bytes.len(),
+		value.x11_size(),
+		"the number of bytes written for {value:?} does not match its `x11_size`",
+	);
+
+	let mut buf = &bytes[skip..];
+	let read = T::read_from(&mut buf).expect("failed to read value back");
+
+	assert_eq!(&read, value, "the value read back does not match");
+	assert!(
+		buf.is_empty(),
+		"{} bytes were left unread after reading {value:?}",
+		buf.len(),
+	);
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn test_assert_roundtrip() {
+		assert_roundtrip!(0x0102_0304_u32);
+		assert_roundtrip!([1_u8, 2, 3], skip = 0);
+	}
+}
@@ -0,0 +1,168 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{Buf, ConstantX11Size, ReadError, ReadResult, Readable, ReadableWithContext};
+
+/// A [`Buf`] wrapper which tracks the absolute offset of the bytes read from
+/// it.
+///
+/// Fields read with [`read_field`] or [`read_field_with`] report the offset
+/// and name of the field in any [`ReadError`], so that a failure to read a
+/// message reads as "failed at offset 4 reading field `colormap`" rather than
+/// only "a conversion failed".
+///
+/// Reading a [`ConstantX11Size`] field from a buffer with too few bytes
+/// remaining returns a [`ReadError::UnexpectedEnd`] error, rather than
+/// panicking.
+///
+/// [`read_field`]: TrackedReader::read_field
+/// [`read_field_with`]: TrackedReader::read_field_with
+#[derive(Debug)]
+pub struct TrackedReader<B: Buf> {
+	buf: B,
+	offset: usize,
+}
+
+impl<B: Buf> TrackedReader<B> {
+	/// Wraps the given `buf`, starting at an offset of `0`.
+	pub const fn new(buf: B) -> Self {
+		Self { buf, offset: 0 }
+	}
+
+	/// The number of bytes which have been read so far.
+	pub const fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Unwraps the inner [`Buf`].
+	pub fn into_inner(self) -> B {
+		self.buf
+	}
+
+	/// Reads the given `field`, reporting the offset at which it was read if
+	/// it fails.
+	///
+	/// # Errors
+	/// Returns a [`ReadError::AtOffset`] error wrapping the error encountered
+	/// while reading the `field`.
+	pub fn read_field<T: Readable>(&mut self, field: &'static str) -> ReadResult<T> {
+		let offset = self.offset;
+
+		self.check_remaining::<T>()
+			.and_then(|()| T::read_from(self))
+			.map_err(|error| ReadError::AtOffset {
+				offset,
+				field,
+				source: Box::new(error),
+			})
+	}
+
+	/// Reads the given `field` with the given `context`, reporting the offset
+	/// at which it was read if it fails.
+	///
+	/// # Errors
+	/// Returns a [`ReadError::AtOffset`] error wrapping the error encountered
+	/// while reading the `field`.
+	pub fn read_field_with<T: ReadableWithContext>(
+		&mut self, field: &'static str, context: &T::Context,
+	) -> ReadResult<T> {
+		let offset = self.offset;
+
+		self.check_remaining::<T>()
+			.and_then(|()| T::read_with(self, context))
+			.map_err(|error| ReadError::AtOffset {
+				offset,
+				field,
+				source: Box::new(error),
+			})
+	}
+
+	fn check_remaining<T>(&self) -> ReadResult<()> {
+		let expected = T::minimum_x11_size();
+		let remaining = self.buf.remaining();
+
+		if remaining < expected {
+			Err(ReadError::UnexpectedEnd {
+				expected,
+				remaining,
+			})
+		} else {
+			Ok(())
+		}
+	}
+}
+
+impl<B: Buf> Buf for TrackedReader<B> {
+	fn remaining(&self) -> usize {
+		self.buf.remaining()
+	}
+
+	fn chunk(&self) -> &[u8] {
+		self.buf.chunk()
+	}
+
+	fn advance(&mut self, cnt: usize) {
+		self.buf.advance(cnt);
+		self.offset += cnt;
+	}
+}
+
+/// The number of bytes which are known to be required to read a type.
+///
+/// This is the [`X11_SIZE`] of [`ConstantX11Size`] types, and `0` otherwise.
+///
+/// [`X11_SIZE`]: ConstantX11Size::X11_SIZE
+trait MinimumX11Size {
+	fn minimum_x11_size() -> usize;
+}
+
+impl<T> MinimumX11Size for T {
+	default fn minimum_x11_size() -> usize {
+		0
+	}
+}
+
+impl<T: ConstantX11Size> MinimumX11Size for T {
+	fn minimum_x11_size() -> usize {
+		T::X11_SIZE
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_tracked_reader_offset() {
+		let bytes = [0, 0, 0, 1, 0, 0, 0, 2];
+		let mut reader = TrackedReader::new(&bytes[..]);
+
+		assert_eq!(reader.read_field::<u32>("window").unwrap(), 1);
+		assert_eq!(reader.offset(), 4);
+		assert_eq!(reader.read_field::<u32>("colormap").unwrap(), 2);
+		assert_eq!(reader.offset(), 8);
+	}
+
+	#[test]
+	fn test_tracked_reader_truncated() {
+		let bytes = [0, 0, 0, 1, 0, 0];
+		let mut reader = TrackedReader::new(&bytes[..]);
+
+		reader.read_field::<u32>("window").unwrap();
+		let error = reader.read_field::<u32>("colormap").unwrap_err();
+
+		assert!(matches!(
+			error,
+			ReadError::AtOffset {
+				offset: 4,
+				field: "colormap",
+				..
+			}
+		));
+		assert_eq!(
+			error.to_string(),
+			"failed at offset 4 reading field `colormap`: expected 4 bytes, but only 2 remain",
+		);
+	}
+}
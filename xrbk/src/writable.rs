@@ -4,7 +4,7 @@
 
 //! [`Writable`] implementations for primitive types
 
-use crate::{Writable, WriteResult};
+use crate::{pad, Writable, WriteResult};
 use bytes::BufMut;
 
 macro_rules! implement {
@@ -44,9 +44,18 @@ implement! {
 	b: &bool => BufMut::put_u8(u8::from(*b)),
 }
 
-impl<T: Writable> Writable for &[T] {
+/// Writes a single unused byte, as for a `_` element.
+impl Writable for () {
 	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
-		for x in *self {
+		writer.put_u8(0);
+
+		Ok(())
+	}
+}
+
+impl<T: Writable> Writable for [T] {
+	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
+		for x in self {
 			x.write_to(writer)?;
 		}
 
@@ -66,15 +75,27 @@ impl<T: Writable, const N: usize> Writable for [T; N] {
 
 impl<T: Writable> Writable for Vec<T> {
 	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
-		for x in self {
-			x.write_to(writer)?;
-		}
+		self.as_slice().write_to(writer)
+	}
+}
+
+impl Writable for str {
+	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
+		writer.put_slice(self.as_bytes());
+		// Pad the string to a multiple of four bytes.
+		writer.put_bytes(0, pad(&self.as_bytes()));
 
 		Ok(())
 	}
 }
 
-impl<T: Writable> Writable for &T {
+impl Writable for String {
+	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
+		self.as_str().write_to(writer)
+	}
+}
+
+impl<T: Writable + ?Sized> Writable for &T {
 	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
 		T::write_to(self, writer)?;
 
@@ -82,7 +103,7 @@ impl<T: Writable> Writable for &T {
 	}
 }
 
-impl<T: Writable> Writable for &mut T {
+impl<T: Writable + ?Sized> Writable for &mut T {
 	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
 		T::write_to(self, writer)?;
 
@@ -90,10 +111,44 @@ impl<T: Writable> Writable for &mut T {
 	}
 }
 
-impl<T: Writable> Writable for Box<T> {
+impl<T: Writable + ?Sized> Writable for Box<T> {
 	fn write_to(&self, writer: &mut impl BufMut) -> WriteResult {
 		T::write_to(self, writer)?;
 
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use crate::test_support::write;
+
+	#[test]
+	fn test_write_through_reference() {
+		let value = 0x0102_u16;
+
+		assert_eq!(write(&&value), [1, 2]);
+		assert_eq!(write(&&&value), [1, 2]);
+	}
+
+	#[test]
+	fn test_write_through_unsized_reference() {
+		let values = [1u16, 2];
+		let slice: &[u16] = &values;
+
+		assert_eq!(write(&slice), [0, 1, 0, 2]);
+		assert_eq!(write(&Box::<[u16]>::from(slice)), [0, 1, 0, 2]);
+	}
+
+	#[test]
+	fn test_write_unit() {
+		assert_eq!(write(&()), [0]);
+		assert_eq!(write(&[(); 3]), [0, 0, 0]);
+	}
+
+	#[test]
+	fn test_write_string_with_padding() {
+		assert_eq!(write(&"hello"), *b"hello\0\0\0");
+		assert_eq!(write(&String::from("four")), *b"four");
+	}
+}
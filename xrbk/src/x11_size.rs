@@ -4,7 +4,7 @@
 
 //! [`X11Size`] and [`ConstantX11Size`] implementations for primitive types
 
-use crate::{ConstantX11Size, X11Size};
+use crate::{pad, ConstantX11Size, X11Size};
 use std::ops::{Range, RangeInclusive};
 
 /// Simple macro for easely defining size for primitive types
@@ -43,9 +43,21 @@ constant_x11_size! {
 	bool,
 }
 
+// `()` is a single unused byte, as for a `_` element.
+
+impl X11Size for () {
+	fn x11_size(&self) -> usize {
+		Self::X11_SIZE
+	}
+}
+
+impl ConstantX11Size for () {
+	const X11_SIZE: usize = 1;
+}
+
 impl<T: X11Size> X11Size for Vec<T> {
 	fn x11_size(&self) -> usize {
-		self.iter().map(X11Size::x11_size).sum()
+		self.as_slice().x11_size()
 	}
 }
 
@@ -61,33 +73,29 @@ impl<T: X11Size, const N: usize> X11Size for [T; N] {
 	}
 }
 
-impl<T: X11Size> X11Size for &[T] {
+impl<T: X11Size> X11Size for [T] {
 	fn x11_size(&self) -> usize {
 		let mut x11_size: usize = 0;
 
-		for x in *self {
-			x11_size += x.x11_size();
+		for element in self {
+			x11_size += element.x11_size();
 		}
 
 		x11_size
 	}
 }
 
-impl<T: X11Size> X11Size for [T] {
+// Unlike `[u8]`, strings are padded to a multiple of four bytes, as they are
+// when they are written. `&str` uses this through the `&T` implementation.
+impl X11Size for str {
 	fn x11_size(&self) -> usize {
-		let mut x11_size: usize = 0;
-
-		for element in self {
-			x11_size += element.x11_size();
-		}
-
-		x11_size
+		self.len() + pad(&self.as_bytes())
 	}
 }
 
-impl X11Size for &str {
+impl X11Size for String {
 	fn x11_size(&self) -> usize {
-		self.len()
+		self.as_str().x11_size()
 	}
 }
 
@@ -103,7 +111,7 @@ impl<T: ConstantX11Size> ConstantX11Size for Option<T> {
 
 // Size for references will be the same as the owned type.
 
-impl<T: X11Size> X11Size for &T {
+impl<T: X11Size + ?Sized> X11Size for &T {
 	default fn x11_size(&self) -> usize {
 		T::x11_size(self)
 	}
@@ -113,7 +121,7 @@ impl<T: ConstantX11Size + X11Size> ConstantX11Size for &T {
 	const X11_SIZE: usize = T::X11_SIZE;
 }
 
-impl<T: X11Size> X11Size for &mut T {
+impl<T: X11Size + ?Sized> X11Size for &mut T {
 	default fn x11_size(&self) -> usize {
 		T::x11_size(self)
 	}
@@ -123,7 +131,7 @@ impl<T: X11Size + ConstantX11Size> ConstantX11Size for &mut T {
 	const X11_SIZE: usize = T::X11_SIZE;
 }
 
-impl<T: X11Size> X11Size for Box<T> {
+impl<T: X11Size + ?Sized> X11Size for Box<T> {
 	default fn x11_size(&self) -> usize {
 		T::x11_size(self)
 	}
@@ -153,9 +161,31 @@ impl<T: X11Size + ConstantX11Size> ConstantX11Size for RangeInclusive<T> {
 	const X11_SIZE: usize = T::X11_SIZE + u8::X11_SIZE;
 }
 
+macro_rules! tuple_x11_size {
+	($(($($T:ident $field:tt),+)),*$(,)?) => {
+		$(
+			impl<$($T: X11Size),+> X11Size for ($($T,)+) {
+				fn x11_size(&self) -> usize {
+					0 $(+ self.$field.x11_size())+
+				}
+			}
+
+			impl<$($T: ConstantX11Size),+> ConstantX11Size for ($($T,)+) {
+				const X11_SIZE: usize = 0 $(+ $T::X11_SIZE)+;
+			}
+		)*
+	};
+}
+
+tuple_x11_size! {
+	(A 0, B 1),
+	(A 0, B 1, C 2),
+	(A 0, B 1, C 2, D 3),
+}
+
 #[cfg(test)]
 mod test {
-	use super::X11Size;
+	use super::{ConstantX11Size, X11Size};
 
 	#[test]
 	fn test_x11_size_vec() {
@@ -169,5 +199,32 @@ mod test {
 		assert_eq!(data.x11_size(), 8);
 	}
 
+	#[test]
+	fn test_x11_size_reference() {
+		let data = [1u16, 2, 3];
+		let slice: &[u16] = &data;
+
+		// Name the `&T` implementations explicitly, as method calls would
+		// auto-deref to the owned types.
+		assert_eq!(<&[u16; 3] as X11Size>::x11_size(&&data), 6);
+		assert_eq!(slice.x11_size(), 6);
+		assert_eq!(<&&[u16] as X11Size>::x11_size(&&slice), 6);
+	}
+
+	#[test]
+	fn test_x11_size_string_is_padded() {
+		assert_eq!("hello".x11_size(), 8);
+		assert_eq!(String::from("hello").x11_size(), 8);
+
+		assert_eq!("".x11_size(), 0);
+		assert_eq!("four".x11_size(), 4);
+	}
+
+	#[test]
+	fn test_x11_size_tuple() {
+		assert_eq!((1u8, vec![2u16, 3]).x11_size(), 5);
+		assert_eq!(<(u8, u16, u32)>::X11_SIZE, 7);
+	}
+
 	// TODO: More tests ?
 }
@@ -5,7 +5,7 @@
 mod expansion;
 pub mod parsing;
 
-use syn::{punctuated::Punctuated, token, Path, Token};
+use syn::{punctuated::Punctuated, token, Expr, Path, Token};
 
 use crate::Source;
 
@@ -29,6 +29,11 @@ pub struct MetabyteAttribute {
 /// An attribute which indicates that a [`Field`] represents the sequence number
 /// of a reply or event.
 ///
+/// The sequence number is both read from and written to its position in the
+/// header: bytes 2 and 3, directly following the metabyte. This means that
+/// events fabricated by a client (e.g. for a `SendEvent` request) are written
+/// with their sequence number intact.
+///
 /// > **<sup>Syntax</sup>**\
 /// > _SequenceAttribute_ :\
 /// > &nbsp;&nbsp; `#` `[` `sequence` `]`
@@ -101,6 +106,47 @@ pub struct ErrorDataAttribute {
 	pub path: Path,
 }
 
+/// An attribute which indicates that a [`Field`] begins at a fixed offset.
+///
+/// The offset is measured in bytes from the start of the message body: the
+/// bytes following the header which `derive_xrb!` reads and writes itself.
+/// A field directly after a request's 4-byte header is at offset `0`. For
+/// structs, the body is the whole struct, and for enum variants, it follows
+/// the discriminant.
+///
+/// When writing, unused bytes are written until the offset is reached; when
+/// reading, bytes are skipped until the offset is reached. If the previous
+/// elements already extend past the offset, an error is generated at read or
+/// write time, and if there are too few bytes left to skip when reading, an
+/// `UnexpectedEnd` error is generated.
+///
+/// > **<sup>Syntax</sup>**\
+/// > _OffsetAttribute_ :\
+/// > &nbsp;&nbsp; `#` `[` `offset` `(` [_Expression_] `)` `]`
+/// >
+/// > [_Expression_]: https://doc.rust-lang.org/reference/expressions.html
+///
+/// [`Field`]: crate::element::Field
+pub struct OffsetAttribute {
+	/// A hash token: `#`.
+	pub hash_token: Token![#],
+	/// A pair of square brackets (`[` and `]`) surrounding the `path`.
+	pub bracket_token: token::Bracket,
+
+	/// The attribute path: `offset` for an `OffsetAttribute`.
+	pub path: Path,
+
+	/// A pair of normal brackets (`(` and `)`) surrounding the `offset`.
+	pub paren_token: token::Paren,
+
+	/// The offset, in bytes, at which the [`Field`] begins.
+	///
+	/// This must evaluate to a `usize`.
+	///
+	/// [`Field`]: crate::element::Field
+	pub offset: Expr,
+}
+
 /// An attribute which indicates that a [`Field`] should not be taken into
 /// consideration when implementing XRBK traits.
 ///
@@ -121,6 +167,10 @@ pub struct ErrorDataAttribute {
 /// > *HiddenTraits*, any
 /// > other traits will have no effects.
 ///
+/// A [`Field`] hidden from `Writable` is never written, so it is also hidden
+/// from `X11Size`, and it is not read either: `Readable` initializes it with
+/// its [`Default`] value.
+///
 /// [`Field`]: crate::element::Field
 pub struct HideAttribute {
 	/// A hash token: `#`.
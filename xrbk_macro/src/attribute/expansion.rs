@@ -62,6 +62,20 @@ impl ToTokens for ErrorDataAttribute {
 	}
 }
 
+impl ToTokens for OffsetAttribute {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		// `#`.
+		self.hash_token.to_tokens(tokens);
+		// Square brackets surrounding `offset` and the offset.
+		self.bracket_token.surround(tokens, |tokens| {
+			self.path.to_tokens(tokens);
+			self.paren_token.surround(tokens, |tokens| {
+				self.offset.to_tokens(tokens);
+			});
+		});
+	}
+}
+
 impl ToTokens for HideAttribute {
 	fn to_tokens(&self, tokens: &mut TokenStream) {
 		// `#`.
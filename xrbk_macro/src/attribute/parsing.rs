@@ -37,6 +37,8 @@ pub struct ParsedAttributes {
 	pub error_data_attribute: Option<ErrorDataAttribute>,
 	/// A hide attribute, if one was parsed.
 	pub hide_attribute: Option<HideAttribute>,
+	/// An offset attribute, if one was parsed.
+	pub offset_attribute: Option<OffsetAttribute>,
 }
 
 pub struct ParsedItemAttributes {
@@ -75,6 +77,7 @@ impl ParseWithContext for ParsedAttributes {
 		let mut major_opcode_attribute = None;
 		let mut error_data_attribute = None;
 		let mut hide_attribute = None;
+		let mut offset_attribute = None;
 
 		// While there are still attributes remaining...
 		while input.peek(Token![#]) && input.peek2(token::Bracket) {
@@ -196,8 +199,25 @@ impl ParseWithContext for ParsedAttributes {
 					paren_token: parenthesized!(inner_content in content),
 					hidden_traits: inner_content.parse_terminated(Path::parse)?,
 				});
-			// Otherwise, if the name was not `context`, `metabyte`, nor
-			// `sequence`, parse the attribute as a normal attribute.
+			// If the name is `offset`, parse it as an offset attribute.
+			} else if path.is_ident("offset") {
+				if offset_attribute.is_some() {
+					return Err(syn::Error::new(
+						path.span(),
+						"no more than one offset attribute is allowed per element",
+					));
+				}
+
+				let inner_content;
+				offset_attribute = Some(OffsetAttribute {
+					hash_token,
+					bracket_token,
+					path,
+					paren_token: parenthesized!(inner_content in content),
+					offset: inner_content.parse()?,
+				});
+			// Otherwise, if the name was not that of an XRBK attribute, parse
+			// the attribute as a normal attribute.
 			} else {
 				attributes.push(Attribute {
 					pound_token: hash_token,
@@ -233,6 +253,7 @@ impl ParseWithContext for ParsedAttributes {
 			major_opcode_attribute,
 			error_data_attribute,
 			hide_attribute,
+			offset_attribute,
 		})
 	}
 }
@@ -41,6 +41,10 @@ impl ToTokens for Definition {
 				for path in &attrs.derive_x11_sizes {
 					r#struct.impl_x11_size(tokens, path);
 				}
+
+				for path in &attrs.derive_constant_x11_sizes {
+					r#struct.impl_constant_x11_size(tokens, path);
+				}
 			},
 
 			Self::Enum(r#enum) => {
@@ -59,6 +63,10 @@ impl ToTokens for Definition {
 				for path in &attrs.derive_x11_sizes {
 					r#enum.impl_x11_size(tokens, path);
 				}
+
+				for path in &attrs.derive_constant_x11_sizes {
+					unsupported_constant_x11_size(tokens, path);
+				}
 			},
 
 			Self::Request(request) => {
@@ -78,6 +86,10 @@ impl ToTokens for Definition {
 				for path in &attrs.derive_x11_sizes {
 					request.impl_x11_size(tokens, path);
 				}
+
+				for path in &attrs.derive_constant_x11_sizes {
+					request.impl_constant_x11_size(tokens, path);
+				}
 			},
 
 			Self::Reply(reply) => {
@@ -97,6 +109,10 @@ impl ToTokens for Definition {
 				for path in &attrs.derive_x11_sizes {
 					reply.impl_x11_size(tokens, path);
 				}
+
+				for path in &attrs.derive_constant_x11_sizes {
+					unsupported_constant_x11_size(tokens, path);
+				}
 			},
 
 			Self::Event(event) => {
@@ -116,6 +132,10 @@ impl ToTokens for Definition {
 				for path in &attrs.derive_x11_sizes {
 					event.impl_x11_size(tokens, path);
 				}
+
+				for path in &attrs.derive_constant_x11_sizes {
+					event.impl_constant_x11_size(tokens, path);
+				}
 			},
 
 			Self::Error(error) => {
@@ -135,6 +155,10 @@ impl ToTokens for Definition {
 				for path in &attrs.derive_x11_sizes {
 					error.impl_x11_size(tokens, path);
 				}
+
+				for path in &attrs.derive_constant_x11_sizes {
+					unsupported_constant_x11_size(tokens, path);
+				}
 			},
 
 			Self::Other(item) => item.to_tokens(tokens),
@@ -142,6 +166,17 @@ impl ToTokens for Definition {
 	}
 }
 
+/// Expands a compile error for a `ConstantX11Size` derive on a definition
+/// which it can't be derived for.
+fn unsupported_constant_x11_size(tokens: &mut TokenStream, path: &Path) {
+	syn::Error::new(
+		path.span(),
+		"`ConstantX11Size` can only be derived for structs, requests, and events",
+	)
+	.to_compile_error()
+	.to_tokens(tokens);
+}
+
 macro_rules! structlike_to_tokens {
 	($Struct:ty) => {
 		impl ToTokens for $Struct {
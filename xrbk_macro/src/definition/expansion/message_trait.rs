@@ -40,6 +40,45 @@ impl Request {
 
 		let request_token = &self.request_token;
 
+		// Extension requests use the metabyte position for their minor opcode,
+		// so `metabyte()` is only generated for a metabyte element otherwise.
+		let metabyte = match self.content.metabyte_element() {
+			Some(element) if self.minor_opcode.is_none() => {
+				let pat = TokenStream2::with_tokens(|tokens| {
+					self.content.pat_cons_to_tokens(tokens);
+				});
+
+				let write = TokenStream2::with_tokens(|tokens| {
+					element.write_tokens(tokens, DefinitionType::Request);
+				});
+
+				quote_spanned!(self.request_token.span()=>
+					#[allow(
+						unused_variables,
+						clippy::items_after_statements,
+						clippy::trivially_copy_pass_by_ref,
+						clippy::needless_borrow,
+						clippy::mut_mut,
+					)]
+					fn metabyte(&self) -> Result<u8, ::xrbk::WriteError> {
+						// Destructure the request struct's fields, if any.
+						let Self #pat = self;
+
+						// The metabyte element is written straight into the single
+						// byte it occupies.
+						let mut metabyte = [0u8; 1];
+						let buf: &mut &mut [u8] = &mut &mut metabyte[..];
+
+						#write
+
+						Ok(metabyte[0])
+					}
+				)
+			},
+
+			_ => TokenStream2::new(),
+		};
+
 		tokens.append_tokens({
 			quote_spanned!(self.request_token.span()=>
 				#[automatically_derived]
@@ -59,6 +98,8 @@ impl Request {
 					fn length(&self) -> u16 {
 						(<Self as ::xrbk::X11Size>::x11_size(self) / 4) as u16
 					}
+
+					#metabyte
 				}
 			)
 		});
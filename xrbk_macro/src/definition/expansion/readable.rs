@@ -54,6 +54,9 @@ impl Struct {
 					// Declare a x11_size variable if it is going to be
 					// used in an infer unused bytes element.
 					let mut size: usize = 0;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 
 					// Read each element.
 					#reads
@@ -121,6 +124,9 @@ impl Request {
 					buf: &mut impl ::xrbk::Buf,
 				) -> Result<Self, ::xrbk::ReadError> {
 					let mut size: usize = 4;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 
 					// If there is a metabyte element, read it, if not and
 					// there is no minor opcode, skip one byte. If there
@@ -200,6 +206,9 @@ impl Reply {
 					buf: &mut impl ::xrbk::Buf,
 				) -> Result<Self, ::xrbk::ReadError> {
 					let mut size: usize = 8;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 
 					// Metabyte position
 					#metabyte
@@ -293,6 +302,9 @@ impl Event {
 					buf: &mut impl ::xrbk::Buf,
 				) -> Result<Self, ::xrbk::ReadError> {
 					let mut size: usize = #x11_size;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 
 					// Metabyte position
 					#metabyte
@@ -306,6 +318,23 @@ impl Event {
 					Ok(Self #cons)
 				}
 			}
+
+			#[automatically_derived]
+			impl #impl_generics ::core::convert::TryFrom<&[u8; 32]> for #ident #type_generics #where_clause {
+				type Error = ::xrbk::ReadError;
+
+				fn try_from(frame: &[u8; 32]) -> Result<Self, Self::Error> {
+					let code = frame[0];
+
+					// Make sure the event code matches this event before reading the
+					// rest of the frame.
+					if !<Self as xrb::message::Event>::matches_code(code) {
+						return Err(::xrbk::ReadError::UnrecognizedDiscriminant(code as usize));
+					}
+
+					<Self as #trait_path>::read_from(&mut &frame[1..])
+				}
+			}
 		));
 	}
 }
@@ -402,6 +431,9 @@ impl Error {
 					// - 2 bytes for the request's minor opcode
 					// - 1 byte for the request's major opcode
 					let mut size: usize = 11;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 
 					#sequence
 					#error_data
@@ -466,6 +498,9 @@ impl Enum {
 
 					quote_spanned!(trait_path.span()=>
 						let mut size: usize = #discrim_type::X11_SIZE;
+						// `#[offset]`s are measured from the start of the body.
+						#[allow(unused_variables)]
+						let body_start: usize = size;
 					)
 				};
 
@@ -50,6 +50,9 @@ impl Struct {
 					buf: &mut impl ::xrbk::BufMut,
 				) -> Result<(), ::xrbk::WriteError> {
 					let mut size: usize = 0;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the struct's fields, if any.
 					let Self #pat = self;
 
@@ -100,7 +103,18 @@ impl Request {
 			)
 		} else if let Some(element) = self.content.metabyte_element() {
 			TokenStream2::with_tokens(|tokens| {
-				element.write_tokens(tokens, DefinitionType::Request);
+				// Later elements may refer to a `let` element in the metabyte
+				// position, so it is still bound here.
+				if let Element::Let(r#let) = element {
+					r#let.function_call_tokens(tokens);
+				}
+
+				tokens.append_tokens(quote_spanned!(trait_path.span()=>
+					<_ as ::xrbk::BufMut>::put_u8(
+						buf,
+						<Self as xrb::message::Request>::metabyte(&self)?,
+					);
+				));
 			})
 		} else {
 			quote_spanned!(trait_path.span()=>
@@ -126,6 +140,9 @@ impl Request {
 					buf: &mut impl ::xrbk::BufMut,
 				) -> Result<(), ::xrbk::WriteError> {
 					let mut size: usize = 4;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the request struct's fields, if any.
 					let Self #pat = self;
 
@@ -210,6 +227,9 @@ impl Reply {
 					buf: &mut impl ::xrbk::BufMut,
 				) -> Result<(), ::xrbk::WriteError> {
 					let mut size: usize = 8;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the reply struct's fields, if any.
 					let Self #pat = self;
 
@@ -309,6 +329,9 @@ impl Event {
 					buf: &mut impl ::xrbk::BufMut,
 				) -> Result<(), ::xrbk::WriteError> {
 					let mut size: usize = #x11_size;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the event struct's fields, if any.
 					let Self #pat = self;
 
@@ -431,6 +454,9 @@ impl Error {
 					// - 2 bytes for the request's minor opcode
 					// - 1 byte for the request's major opcode
 					let mut size: usize = 11;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the error struct's fields, if any.
 					let Self #pat = self;
 
@@ -509,6 +535,9 @@ impl Enum {
 
 					Some(quote_spanned!(trait_path.span()=>
 						let mut size: usize = #discrim_type::X11_SIZE;
+						// `#[offset]`s are measured from the start of the body.
+						#[allow(unused_variables)]
+						let body_start: usize = size;
 					))
 				};
 
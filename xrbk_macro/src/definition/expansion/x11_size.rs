@@ -4,7 +4,7 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote_spanned;
-use syn::Path;
+use syn::{Generics, Ident, Path};
 
 use crate::{element::Element, TsExt};
 
@@ -44,6 +44,9 @@ impl Struct {
 				)]
 				fn x11_size(&self) -> usize {
 					let mut size: usize = 0;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the struct's fields, if any.
 					let Self #pat = self;
 
@@ -58,6 +61,21 @@ impl Struct {
 	}
 }
 
+impl Struct {
+	pub fn impl_constant_x11_size(&self, tokens: &mut TokenStream2, trait_path: &Path) {
+		let size = constant_x11_size_sum(trait_path, &self.content, 0, DefinitionType::Basic);
+
+		impl_constant_x11_size(
+			tokens,
+			trait_path,
+			&self.ident,
+			&self.generics,
+			&self.content,
+			&size,
+		);
+	}
+}
+
 impl Request {
 	pub fn impl_x11_size(&self, tokens: &mut TokenStream2, trait_path: &Path) {
 		let ident = &self.ident;
@@ -98,6 +116,9 @@ impl Request {
 					// The size starts at `4` to account for the size
 					// of a request's header being 4 bytes.
 					let mut size: usize = 4;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the request's fields, if any.
 					let Self #pat = self;
 
@@ -112,6 +133,23 @@ impl Request {
 	}
 }
 
+impl Request {
+	pub fn impl_constant_x11_size(&self, tokens: &mut TokenStream2, trait_path: &Path) {
+		// The size starts at `4` to account for the size of a request's
+		// header being 4 bytes.
+		let size = constant_x11_size_sum(trait_path, &self.content, 4, DefinitionType::Request);
+
+		impl_constant_x11_size(
+			tokens,
+			trait_path,
+			&self.ident,
+			&self.generics,
+			&self.content,
+			&size,
+		);
+	}
+}
+
 impl Reply {
 	pub fn impl_x11_size(&self, tokens: &mut TokenStream2, trait_path: &Path) {
 		let ident = &self.ident;
@@ -152,6 +190,9 @@ impl Reply {
 					// The size starts at `8` to account for the size
 					// of a reply's header being 8 bytes.
 					let mut size: usize = 8;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the reply's fields, if any.
 					let Self #pat = self;
 
@@ -214,6 +255,9 @@ impl Event {
 					// position, to account for the size of the event's
 					// header.
 					let mut size: usize = #size;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the event's fields, if any.
 					let Self #pat = self;
 
@@ -228,6 +272,24 @@ impl Event {
 	}
 }
 
+impl Event {
+	// Events are sent in `SendEvent` requests, which require their event to
+	// implement `ConstantX11Size`.
+	pub fn impl_constant_x11_size(&self, tokens: &mut TokenStream2, trait_path: &Path) {
+		// Events are always 32 bytes.
+		let size = quote_spanned!(trait_path.span()=> 32);
+
+		impl_constant_x11_size(
+			tokens,
+			trait_path,
+			&self.ident,
+			&self.generics,
+			&self.content,
+			&size,
+		);
+	}
+}
+
 impl Error {
 	pub fn impl_x11_size(&self, tokens: &mut TokenStream2, trait_path: &Path) {
 		let ident = &self.ident;
@@ -267,6 +329,9 @@ impl Error {
 				fn x11_size(&self) -> usize {
 					// At least 11 bytes including all the required fields.
 					let mut size: usize = 11;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 					// Destructure the error's fields, if any.
 					let Self #pat = self;
 
@@ -330,6 +395,9 @@ impl Enum {
 				)]
 				fn x11_size(&self) -> usize {
 					let mut size: usize = #discrim_type::X11_SIZE;
+					// `#[offset]`s are measured from the start of the body.
+					#[allow(unused_variables)]
+					let body_start: usize = size;
 
 					match self {
 						#arms
@@ -342,3 +410,59 @@ impl Enum {
 		));
 	}
 }
+
+/// Expands the `const` expression which adds up the [`ConstantX11Size`]s of
+/// the given `content`'s elements, starting from `header_size`.
+///
+/// [`ConstantX11Size`]: https://docs.rs/xrbk/latest/xrbk/trait.ConstantX11Size.html
+fn constant_x11_size_sum(
+	trait_path: &Path, content: &StructlikeContent, header_size: usize,
+	definition_type: DefinitionType,
+) -> TokenStream2 {
+	let sizes = TokenStream2::with_tokens(|tokens| {
+		for element in content {
+			if element.is_normal() {
+				element.constant_x11_size_tokens(tokens, definition_type);
+			}
+		}
+	});
+
+	quote_spanned!(trait_path.span()=>
+		{
+			let mut size: usize = #header_size;
+			// `#[offset]`s are measured from the start of the body.
+			#[allow(unused_variables)]
+			let body_start: usize = size;
+
+			// Add the size of each element.
+			#sizes
+
+			size
+		}
+	)
+}
+
+/// Expands an implementation of [`ConstantX11Size`] with the given `size` as
+/// its `X11_SIZE`.
+///
+/// [`ConstantX11Size`]: https://docs.rs/xrbk/latest/xrbk/trait.ConstantX11Size.html
+fn impl_constant_x11_size(
+	tokens: &mut TokenStream2, trait_path: &Path, ident: &Ident, generics: &Generics,
+	content: &StructlikeContent, size: &TokenStream2,
+) {
+	// TODO: add generic bounds
+	let (impl_generics, type_generics, _) = generics.split_for_impl();
+	let where_clause = match content {
+		StructlikeContent::Regular { where_clause, .. } => where_clause,
+		StructlikeContent::Tuple { where_clause, .. } => where_clause,
+		StructlikeContent::Unit { where_clause, .. } => where_clause,
+	};
+
+	tokens.append_tokens(quote_spanned!(trait_path.span()=>
+		#[automatically_derived]
+		impl #impl_generics ::xrbk::ConstantX11Size for #ident #type_generics #where_clause {
+			#[allow(clippy::identity_op, unused_mut)]
+			const X11_SIZE: usize = #size;
+		}
+	));
+}
@@ -4,7 +4,19 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote, ToTokens};
-use syn::{punctuated::Pair, Attribute, Data, Fields, FieldsNamed, FieldsUnnamed, Index, Type};
+use syn::{
+	punctuated::Pair,
+	Attribute,
+	Data,
+	DataEnum,
+	DeriveInput,
+	Fields,
+	FieldsNamed,
+	FieldsUnnamed,
+	Ident,
+	Index,
+	Type,
+};
 
 use crate::TsExt;
 
@@ -140,6 +152,80 @@ pub fn unwrap_return(fields: &Fields) -> TokenStream2 {
 	})
 }
 
+/// Converts an `UpperCamelCase` identifier to `snake_case`.
+fn snake_case(ident: &Ident) -> String {
+	let ident = ident.to_string();
+	let chars: Vec<char> = ident.chars().collect();
+
+	let mut snake_case = String::with_capacity(ident.len());
+
+	for (i, &char) in chars.iter().enumerate() {
+		if char.is_uppercase() && i != 0 {
+			let previous = chars[i - 1];
+			let next = chars.get(i + 1);
+
+			// `FooBar` -> `foo_bar`, `RGBColor` -> `rgb_color`.
+			if !previous.is_uppercase() || next.is_some_and(|next| next.is_lowercase()) {
+				snake_case.push('_');
+			}
+		}
+
+		snake_case.extend(char.to_lowercase());
+	}
+
+	snake_case
+}
+
+/// Generates an `unwrap_variant` method for each variant of the given `enum`.
+///
+/// Each method returns the variant's fields if `self` is that variant, and
+/// panics otherwise.
+pub fn derive_enum_unwraps(item: &DeriveInput, r#enum: &DataEnum) -> TokenStream2 {
+	let ident = &item.ident;
+	let (impl_generics, type_generics, where_clause) = item.generics.split_for_impl();
+
+	let methods = r#enum.variants.iter().map(|variant| {
+		let variant_ident = &variant.ident;
+		let method = format_ident!("unwrap_{}", snake_case(variant_ident));
+
+		let r#return = unwrap_return(&variant.fields);
+		let pat = pat_cons(&variant.fields);
+		let names = names(&variant.fields);
+
+		let doc = format!(
+			"Returns the fields of the [`{variant_ident}`](Self::{variant_ident}) variant."
+		);
+		let panics = format!("Panics if `self` is not `{ident}::{variant_ident}`.");
+		let message = format!("called `{ident}::{method}()` on a value that is not `{ident}::{variant_ident}`");
+		// Unwrapping a variant without fields only checks which variant it is.
+		let must_use = (!variant.fields.is_empty()).then(|| quote!(#[must_use]));
+
+		quote!(
+			#[doc = #doc]
+			#[doc = ""]
+			#[doc = "# Panics"]
+			#[doc = #panics]
+			#must_use
+			#[track_caller]
+			pub fn #method(self) -> (#r#return) {
+				match self {
+					Self::#variant_ident #pat => (#names),
+
+					#[allow(unreachable_patterns)]
+					_ => panic!(#message),
+				}
+			}
+		)
+	});
+
+	quote!(
+		#[automatically_derived]
+		impl #impl_generics #ident #type_generics #where_clause {
+			#(#methods)*
+		}
+	)
+}
+
 pub fn integer_type(data: &Data) -> &Type {
 	match data {
 		Data::Struct(data) => {
@@ -477,7 +563,9 @@ pub fn derive_x11_sizes(attributes: &[Attribute], data: &Data) -> TokenStream2 {
 	}
 }
 
-pub fn derive_constant_x11_sizes(_attributes: &[Attribute], data: &Data) -> TokenStream2 {
+pub fn derive_constant_x11_sizes(
+	attributes: &[Attribute], data: &Data,
+) -> syn::Result<TokenStream2> {
 	fn derive_for_fields(fields: &Fields) -> TokenStream2 {
 		TokenStream2::with_tokens(|tokens| match fields {
 			Fields::Named(FieldsNamed { named: fields, .. })
@@ -499,17 +587,53 @@ pub fn derive_constant_x11_sizes(_attributes: &[Attribute], data: &Data) -> Toke
 		})
 	}
 
+	let discrim = if attributes.iter().any(|attr| attr.path.is_ident("no_discrim")) {
+		quote!(0)
+	} else {
+		quote!(1)
+	};
+
 	match data {
 		Data::Struct(r#struct) => {
 			let sizes = derive_for_fields(&r#struct.fields);
 
-			quote!(
+			Ok(quote!(
 				let mut size = 0;
 
 				#sizes
 
 				size
-			)
+			))
+		},
+
+		// Enums with no variants can never be written, so they have no size.
+		Data::Enum(r#enum) if r#enum.variants.is_empty() => Err(syn::Error::new(
+			r#enum.enum_token.span,
+			"cannot derive `ConstantX11Size` for an enum with no variants",
+		)),
+
+		// Enums with no fields are only their discriminant.
+		Data::Enum(r#enum)
+			if r#enum
+				.variants
+				.iter()
+				.all(|variant| matches!(variant.fields, Fields::Unit)) =>
+		{
+			Ok(discrim)
+		},
+
+		// Enums with a single variant are its discriminant followed by its
+		// fields.
+		Data::Enum(r#enum) if r#enum.variants.len() == 1 => {
+			let sizes = derive_for_fields(&r#enum.variants[0].fields);
+
+			Ok(quote!(
+				let mut size = #discrim;
+
+				#sizes
+
+				size
+			))
 		},
 
 		// TODO: derive for enums if all variants are the same constant size
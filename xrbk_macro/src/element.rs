@@ -11,10 +11,13 @@ use syn::{
 	punctuated::Punctuated,
 	token,
 	Attribute,
+	GenericArgument,
 	Ident,
 	Index,
+	PathArguments,
 	Token,
 	Type,
+	TypePath,
 	Visibility,
 	WhereClause,
 };
@@ -27,6 +30,7 @@ use crate::{
 		MajorOpcodeAttribute,
 		MetabyteAttribute,
 		MinorOpcodeAttribute,
+		OffsetAttribute,
 		SequenceAttribute,
 	},
 	source::Source,
@@ -357,12 +361,13 @@ pub struct Elements {
 	pub error_data_element: Option<Element>,
 
 	/// Whether there is an [`ArrayUnused`] element with
-	/// [`UnusedContent::Infer`] within these `Elements`.
+	/// [`UnusedContent::Infer`] or a [`Field`] with an [`OffsetAttribute`]
+	/// within these `Elements`.
 	///
 	/// This is used because if there is no [`UnusedContent::Infer`]
-	/// [`ArrayUnused`] element, the cumulative data size of previous elements
-	/// does not need to be kept track of during serialization and
-	/// deserialization.
+	/// [`ArrayUnused`] element and no [`OffsetAttribute`], the cumulative data
+	/// size of previous elements does not need to be kept track of during
+	/// serialization and deserialization.
 	pub contains_infer: bool,
 }
 
@@ -503,7 +508,8 @@ impl Element {
 /// >
 /// > _FieldAttribute_ :\
 /// > &nbsp;&nbsp; [_OuterAttribute_] | [_ContextAttribute_] |
-/// > [_MetabyteAttribute_] | [_SequenceAttribute_] | [_HideAttribute_]
+/// > [_MetabyteAttribute_] | [_SequenceAttribute_] | [_HideAttribute_] |
+/// > [_OffsetAttribute_]
 /// >
 /// > [_Visibility_]: https://doc.rust-lang.org/reference/visibility-and-privacy.html
 /// > [IDENTIFIER]: https://doc.rust-lang.org/reference/identifiers.html
@@ -514,6 +520,7 @@ impl Element {
 /// > [_MetabyteAttribute_]: MetabyteAttribute
 /// > [_SequenceAttribute_]: SequenceAttribute
 /// > [_HideAttribute_]: HideAttribute
+/// > [_OffsetAttribute_]: OffsetAttribute
 pub struct Field {
 	/// Attributes associated with the `Field`.
 	pub attributes: Vec<Attribute>,
@@ -557,6 +564,11 @@ pub struct Field {
 	///
 	/// See [`HideAttribute`] for more information.
 	pub hide_attribute: Option<HideAttribute>,
+	/// An optional [`OffsetAttribute`] which indicates that this field begins
+	/// at a fixed offset.
+	///
+	/// See [`OffsetAttribute`] for more information.
+	pub offset_attribute: Option<OffsetAttribute>,
 
 	/// The visibility of the `Field`.
 	pub visibility: Visibility,
@@ -626,6 +638,44 @@ impl Field {
 			false
 		}
 	}
+
+	/// Whether this `Field` is excluded from its [`X11Size`] implementation.
+	///
+	/// A `Field` which is hidden from [`Writable`] is never written, so it is
+	/// excluded from [`X11Size`] too, even if [`X11Size`] is not explicitly
+	/// specified in its [`HideAttribute`].
+	///
+	/// [`X11Size`]: https://docs.rs/xrbk/latest/xrbk/trait.X11Size.html
+	/// [`Writable`]: https://docs.rs/xrbk/latest/xrbk/trait.Writable.html
+	pub fn is_ignoring_x11_size(&self) -> bool {
+		self.is_ignoring_trait("X11Size") || self.is_ignoring_trait("Writable")
+	}
+
+	/// Whether this `Field`'s type is `Vec<u8>`.
+	///
+	/// A `Vec<u8>` read with a context is read in a single copy with
+	/// [`read_bytes`], rather than one byte at a time.
+	///
+	/// [`read_bytes`]: https://docs.rs/xrbk/latest/xrbk/fn.read_bytes.html
+	pub fn is_byte_vec(&self) -> bool {
+		let Type::Path(TypePath { qself: None, path }) = &self.r#type else {
+			return false;
+		};
+		let Some(segment) = path.segments.last() else {
+			return false;
+		};
+		let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+			return false;
+		};
+
+		segment.ident == "Vec"
+			&& arguments.args.len() == 1
+			&& matches!(
+				arguments.args.first(),
+				Some(GenericArgument::Type(Type::Path(TypePath { qself: None, path })))
+					if path.is_ident("u8")
+			)
+	}
 }
 
 pub enum FieldId {
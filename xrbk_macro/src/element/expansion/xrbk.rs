@@ -27,7 +27,7 @@ impl Element {
 	pub fn x11_size_tokens(&self, tokens: &mut TokenStream2, definition_type: DefinitionType) {
 		match self {
 			Self::Field(field) => {
-				if !field.is_ignoring_trait("X11Size") {
+				if !field.is_ignoring_x11_size() {
 					field.x11_size_tokens(tokens)
 				}
 			},
@@ -41,7 +41,12 @@ impl Element {
 	pub fn read_tokens(&self, tokens: &mut TokenStream2, definition_type: DefinitionType) {
 		match self {
 			Self::Field(field) => {
-				if !field.is_ignoring_trait("Readable") || field.context_attribute.is_some() {
+				// A field hidden from `Writable` is never written, so there are
+				// no bytes to read for it.
+				if field.is_ignoring_trait("Writable") {
+					field.default_tokens(tokens)
+				} else if !field.is_ignoring_trait("Readable") || field.context_attribute.is_some()
+				{
 					field.read_tokens(tokens)
 				}
 			},
@@ -52,10 +57,91 @@ impl Element {
 		}
 	}
 
+	/// Expands the tokens which add this `Element`'s size to `size` in a
+	/// `const` context for [`ConstantX11Size`].
+	///
+	/// [`ConstantX11Size`]: https://docs.rs/xrbk/latest/xrbk/trait.ConstantX11Size.html
+	pub fn constant_x11_size_tokens(
+		&self, tokens: &mut TokenStream2, definition_type: DefinitionType,
+	) {
+		match self {
+			Self::Field(field) => {
+				if !field.is_ignoring_x11_size() {
+					if let Some(OffsetAttribute { offset, .. }) = &field.offset_attribute {
+						let offset_ident = format_ident!("offset_{}", field.formatted);
+
+						tokens.append_tokens(quote_spanned!(offset.span()=>
+							let #offset_ident: usize = body_start + #offset;
+
+							if size < #offset_ident {
+								size = #offset_ident;
+							}
+						));
+					}
+
+					let r#type = &field.r#type;
+
+					tokens.append_tokens(quote_spanned!(r#type.span()=>
+						size += <#r#type as ::xrbk::ConstantX11Size>::X11_SIZE;
+					));
+				}
+			},
+
+			Self::Let(r#let) => {
+				let r#type = &r#let.r#type;
+
+				tokens.append_tokens(quote_spanned!(r#type.span()=>
+					size += <#r#type as ::xrbk::ConstantX11Size>::X11_SIZE;
+				));
+			},
+
+			Self::SingleUnused(unused) => unused.add_x11_size_tokens(tokens),
+
+			Self::ArrayUnused(unused) => match &unused.content {
+				UnusedContent::Infer { last_element, .. } => {
+					tokens.append_tokens(match definition_type.min_length() {
+						Some(min_length) if *last_element => {
+							quote_spanned!(unused.span()=>
+								size += if size < #min_length {
+									#min_length - size
+								} else {
+									(4 - (size % 4)) % 4
+								};
+							)
+						},
+
+						_ => {
+							quote_spanned!(unused.span()=>
+								size += (4 - (size % 4)) % 4;
+							)
+						},
+					});
+				},
+
+				// A source without arguments is a constant expression.
+				UnusedContent::Source(source) if source.args.is_none() => {
+					let expr = &source.expr;
+
+					tokens.append_tokens(quote_spanned!(unused.span()=>
+						size += #expr;
+					));
+				},
+
+				UnusedContent::Source(_) => {
+					tokens.append_tokens(quote_spanned!(unused.span()=>
+						::core::compile_error!(
+							"`ConstantX11Size` cannot be derived with a sourced number of unused bytes"
+						);
+					));
+				},
+			},
+		}
+	}
+
 	pub fn add_x11_size_tokens(&self, tokens: &mut TokenStream2) {
 		match self {
 			Self::Field(field) => {
-				if !field.is_ignoring_trait("X11Size") {
+				if !field.is_ignoring_x11_size() {
 					field.add_x11_size_tokens(tokens)
 				}
 			},
@@ -70,10 +156,48 @@ impl Element {
 // Field {{{
 
 impl Field {
+	/// Expands the tokens to reach this `Field`'s [`OffsetAttribute`], if it
+	/// has one.
+	///
+	/// `error` is the path to the error type used if previous elements extend
+	/// past the offset, and `skip` expands to the tokens to skip over (or
+	/// write) the given number of unused bytes.
+	fn offset_tokens(
+		&self, tokens: &mut TokenStream2, error: TokenStream2,
+		skip: impl FnOnce(&Ident) -> TokenStream2,
+	) {
+		if let Some(OffsetAttribute { offset, .. }) = &self.offset_attribute {
+			let id = self.id.to_string();
+			let offset_ident = format_ident!("offset_{}", self.formatted);
+			let padding = format_ident!("padding_{}", self.formatted);
+			let skip = skip(&padding);
+
+			tokens.append_tokens(quote_spanned!(offset.span()=>
+				let #offset_ident: usize = #offset;
+
+				let Some(#padding) = (body_start + #offset_ident).checked_sub(size) else {
+					return Err(#error::MisplacedField {
+						field: #id,
+						offset: #offset_ident,
+						size: size - body_start,
+					});
+				};
+				#skip
+				size += #padding;
+			));
+		}
+	}
+
 	pub fn write_tokens(&self, tokens: &mut TokenStream2) {
 		let formatted = &self.formatted;
 		let r#type = &self.r#type;
 
+		self.offset_tokens(tokens, quote!(::xrbk::WriteError), |padding| {
+			quote_spanned!(self.span()=>
+				<_ as ::xrbk::BufMut>::put_bytes(buf, 0u8, #padding);
+			)
+		});
+
 		tokens.append_tokens({
 			let r#type = quote_spanned!(r#type.span()=>
 				<#r#type as ::xrbk::Writable>
@@ -86,6 +210,15 @@ impl Field {
 	}
 
 	pub fn x11_size_tokens(&self, tokens: &mut TokenStream2) {
+		if let Some(OffsetAttribute { offset, .. }) = &self.offset_attribute {
+			let offset_ident = format_ident!("offset_{}", self.formatted);
+
+			tokens.append_tokens(quote_spanned!(offset.span()=>
+				let #offset_ident: usize = body_start + #offset;
+				size = ::core::cmp::max(size, #offset_ident);
+			));
+		}
+
 		self.add_x11_size_tokens(tokens);
 	}
 
@@ -93,6 +226,22 @@ impl Field {
 		let formatted = &self.formatted;
 		let r#type = &self.r#type;
 
+		self.offset_tokens(tokens, quote!(::xrbk::ReadError), |padding| {
+			quote_spanned!(self.span()=>
+				{
+					let remaining = <_ as ::xrbk::Buf>::remaining(buf);
+
+					if remaining < #padding {
+						return Err(::xrbk::ReadError::UnexpectedEnd {
+							expected: #padding,
+							remaining,
+						});
+					}
+				}
+				<_ as ::xrbk::Buf>::advance(buf, #padding);
+			)
+		});
+
 		match &self.context_attribute {
 			Some(ContextAttribute { context, .. }) => {
 				context.source().function_to_tokens(
@@ -108,7 +257,12 @@ impl Field {
 					context.source().call_to_tokens(tokens, formatted);
 				});
 
-				tokens.append_tokens({
+				tokens.append_tokens(if self.is_byte_vec() {
+					// Read all of the bytes in a single copy.
+					quote_spanned!(self.span()=>
+						let #formatted = ::xrbk::read_bytes(buf, #function_call)?;
+					)
+				} else {
 					let r#type = quote_spanned!(r#type.span()=>
 						<#r#type as ::xrbk::ReadableWithContext>
 					);
@@ -136,6 +290,17 @@ impl Field {
 		}
 	}
 
+	/// Expands the tokens to initialize this `Field` with its [`Default`]
+	/// value, rather than reading it.
+	pub fn default_tokens(&self, tokens: &mut TokenStream2) {
+		let formatted = &self.formatted;
+		let r#type = &self.r#type;
+
+		tokens.append_tokens(quote_spanned!(self.span()=>
+			let #formatted = <#r#type as ::core::default::Default>::default();
+		));
+	}
+
 	pub fn add_x11_size_tokens(&self, tokens: &mut TokenStream2) {
 		tokens.append_tokens({
 			let r#type = &self.r#type;
@@ -257,7 +422,7 @@ impl SingleUnused {
 	pub fn write_tokens(&self, tokens: &mut TokenStream2) {
 		tokens.append_tokens({
 			quote_spanned!(self.span()=>
-				<_ as ::xrbk::BufMut>::put_u8(buf, 0);
+				<() as ::xrbk::Writable>::write_to(&(), buf)?;
 			)
 		});
 	}
@@ -269,7 +434,7 @@ impl SingleUnused {
 	pub fn read_tokens(&self, tokens: &mut TokenStream2) {
 		tokens.append_tokens({
 			quote_spanned!(self.span()=>
-				<_ as ::xrbk::Buf>::advance(buf, 1);
+				<() as ::xrbk::Readable>::read_from(buf)?;
 			)
 		});
 	}
@@ -277,7 +442,7 @@ impl SingleUnused {
 	pub fn add_x11_size_tokens(&self, tokens: &mut TokenStream2) {
 		tokens.append_tokens({
 			quote_spanned!(self.span()=>
-				size += 1;
+				size += <() as ::xrbk::ConstantX11Size>::X11_SIZE;
 			)
 		});
 	}
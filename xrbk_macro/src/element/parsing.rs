@@ -271,12 +271,15 @@ impl ParseWithContext for Elements {
 			}
 		}
 
-		let contains_infer = elements.iter().any(|item| {
-			if let ElementsItem::Element(Element::ArrayUnused(unused)) = &item {
+		let contains_infer = elements.iter().any(|item| match item {
+			ElementsItem::Element(Element::ArrayUnused(unused)) => {
 				matches!(unused.content, UnusedContent::Infer { .. })
-			} else {
-				false
-			}
+			},
+			// Fields with an offset attribute need the size to be kept track of
+			// too.
+			ElementsItem::Element(Element::Field(field)) => field.offset_attribute.is_some(),
+
+			_ => false,
 		});
 
 		match (&definition_type, &sequence_element) {
@@ -438,6 +441,7 @@ impl ParseWithContext for SingleUnused {
 			major_opcode_attribute,
 			error_data_attribute,
 			hide_attribute,
+			offset_attribute,
 		}: ParsedAttributes,
 	) -> Result<Self> {
 		if let Some(attribute) = attributes.first() {
@@ -489,6 +493,13 @@ impl ParseWithContext for SingleUnused {
 			));
 		}
 
+		if let Some(attribute) = offset_attribute {
+			return Err(syn::Error::new(
+				attribute.span(),
+				"offset attributes are not allowed for singular unused bytes elements",
+			));
+		}
+
 		Ok(Self {
 			attribute: metabyte_attribute,
 			underscore_token: input.parse()?,
@@ -518,6 +529,7 @@ impl ParseWithContext for ArrayUnused {
 				major_opcode_attribute,
 				error_data_attribute,
 				hide_attribute,
+				offset_attribute,
 			},
 			bracket_token,
 			maps,
@@ -576,6 +588,13 @@ impl ParseWithContext for ArrayUnused {
 			));
 		}
 
+		if let Some(attribute) = offset_attribute {
+			return Err(syn::Error::new(
+				attribute.span(),
+				"offset attributes are not allowed for array-type unused bytes elements",
+			));
+		}
+
 		Ok(Self {
 			formatted: format_ident!("unused_{}", unused_index),
 
@@ -630,6 +649,7 @@ impl ParseWithContext for Let {
 				major_opcode_attribute,
 				error_data_attribute,
 				hide_attribute,
+				offset_attribute,
 			},
 			let_map,
 			definition_type,
@@ -673,6 +693,13 @@ impl ParseWithContext for Let {
 			));
 		}
 
+		if let Some(attribute) = offset_attribute {
+			return Err(syn::Error::new(
+				attribute.span(),
+				"offset attributes are not allowed for let elements",
+			));
+		}
+
 		let let_token = input.parse()?;
 
 		let ident: Ident = input.parse()?;
@@ -722,6 +749,7 @@ impl ParseWithContext for Field {
 				major_opcode_attribute,
 				error_data_attribute,
 				hide_attribute,
+				offset_attribute,
 			},
 			map,
 		): Self::Context<'_>,
@@ -729,6 +757,19 @@ impl ParseWithContext for Field {
 	where
 		Self: Sized,
 	{
+		if let Some(attribute) = &offset_attribute
+			&& (metabyte_attribute.is_some()
+				|| sequence_attribute.is_some()
+				|| minor_opcode_attribute.is_some()
+				|| major_opcode_attribute.is_some()
+				|| error_data_attribute.is_some())
+		{
+			return Err(syn::Error::new(
+				attribute.span(),
+				"offset attributes are not allowed for fields in the header",
+			));
+		}
+
 		let visibility = input.parse()?;
 
 		let id = match element_type {
@@ -759,6 +800,7 @@ impl ParseWithContext for Field {
 			major_opcode_attribute,
 			error_data_attribute,
 			hide_attribute,
+			offset_attribute,
 
 			visibility,
 			id,
@@ -8,7 +8,7 @@
 
 use proc_macro::TokenStream;
 
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{parse_macro_input, Data, DeriveInput, Fields, FieldsNamed, FieldsUnnamed};
 
 pub(crate) use definition::*;
@@ -57,7 +57,16 @@ pub fn derive_unwrap(item: TokenStream) -> TokenStream {
 
 	let fields = match &item.data {
 		Data::Struct(r#struct) => &r#struct.fields,
-		Data::Enum(_) | Data::Union(_) => unimplemented!(),
+		Data::Enum(r#enum) => return derive_enum_unwraps(&item, r#enum).into(),
+
+		Data::Union(_) => {
+			return syn::Error::new_spanned(
+				&item.ident,
+				"`unwrap` can only be derived for structs and enums",
+			)
+			.to_compile_error()
+			.into();
+		},
 	};
 
 	let ident = &item.ident;
@@ -99,6 +108,200 @@ pub fn derive_unwrap(item: TokenStream) -> TokenStream {
 	.into()
 }
 
+#[proc_macro_derive(from_variants)]
+pub fn derive_from_variants(item: TokenStream) -> TokenStream {
+	let item = parse_macro_input!(item as DeriveInput);
+
+	let variants = match &item.data {
+		Data::Enum(r#enum) => &r#enum.variants,
+
+		Data::Struct(_) | Data::Union(_) => {
+			return syn::Error::new_spanned(
+				&item.ident,
+				"`from_variants` can only be derived for enums",
+			)
+			.to_compile_error()
+			.into();
+		},
+	};
+
+	let ident = &item.ident;
+	let (impl_generics, type_generics, where_clause) = item.generics.split_for_impl();
+
+	// Only variants with a single unnamed field wrap a value; other variants,
+	// like `CurrentTime`, are sentinels.
+	let wrapped = variants.iter().filter_map(|variant| match &variant.fields {
+		Fields::Unnamed(FieldsUnnamed { unnamed, .. }) if unnamed.len() == 1 => {
+			Some((&variant.ident, &unnamed[0].ty))
+		},
+
+		_ => None,
+	});
+
+	let mut types = Vec::new();
+	let mut impls = Vec::new();
+
+	for (variant_ident, r#type) in wrapped {
+		// Two `From` impls for the same type would conflict, so point at the
+		// variant instead of leaving it to the compiler.
+		let type_string = r#type.to_token_stream().to_string();
+
+		if types.contains(&type_string) {
+			return syn::Error::new_spanned(
+				r#type,
+				format!("`from_variants` found more than one variant wrapping `{type_string}`"),
+			)
+			.to_compile_error()
+			.into();
+		}
+
+		types.push(type_string);
+
+		impls.push(quote!(
+			#[automatically_derived]
+			impl #impl_generics ::core::convert::From<#r#type>
+				for #ident #type_generics #where_clause
+			{
+				fn from(value: #r#type) -> Self {
+					Self::#variant_ident(value)
+				}
+			}
+		));
+	}
+
+	quote!(#(#impls)*).into()
+}
+
+#[proc_macro_derive(builder)]
+pub fn derive_builder(item: TokenStream) -> TokenStream {
+	let item = parse_macro_input!(item as DeriveInput);
+
+	let fields = match &item.data {
+		Data::Struct(r#struct) => match &r#struct.fields {
+			Fields::Named(FieldsNamed { named: fields, .. }) => fields,
+
+			fields => {
+				return syn::Error::new_spanned(
+					fields,
+					"`builder` can only be derived for structs with named fields",
+				)
+				.to_compile_error()
+				.into();
+			},
+		},
+
+		Data::Enum(_) | Data::Union(_) => {
+			return syn::Error::new_spanned(
+				&item.ident,
+				"`builder` can only be derived for structs with named fields",
+			)
+			.to_compile_error()
+			.into();
+		},
+	};
+
+	let vis = &item.vis;
+	let ident = &item.ident;
+	let builder = format_ident!("{}Builder", ident);
+	let error = format_ident!("{}BuilderError", ident);
+
+	let generics = &item.generics;
+	let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+	let idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+	let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+	let names: Vec<_> = idents
+		.iter()
+		.filter_map(|ident| ident.as_ref().map(ToString::to_string))
+		.collect();
+
+	quote!(
+		#[doc = concat!("A builder used to construct a new [`", stringify!(#ident), "`].")]
+		#[doc = ""]
+		#[doc = concat!(
+			"Every field must be set before [`build`](",
+			stringify!(#builder),
+			"::build) is called.",
+		)]
+		#[derive(Clone, Debug)]
+		#vis struct #builder #generics #where_clause {
+			#(#idents: ::core::option::Option<#types>,)*
+		}
+
+		#[doc = concat!(
+			"An error returned by [`",
+			stringify!(#builder),
+			"::build`] if a field was not set.",
+		)]
+		#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+		#vis struct #error {
+			/// The name of the field which was not set.
+			pub field: &'static str,
+		}
+
+		#[automatically_derived]
+		impl ::core::fmt::Display for #error {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				write!(f, "field `{}` was not set", self.field)
+			}
+		}
+
+		#[automatically_derived]
+		impl ::std::error::Error for #error {}
+
+		#[automatically_derived]
+		impl #impl_generics #ident #type_generics #where_clause {
+			#[doc = concat!(
+				"Returns a new [`",
+				stringify!(#builder),
+				"`] with which a `",
+				stringify!(#ident),
+				"` can be built.",
+			)]
+			#[must_use]
+			pub const fn builder() -> #builder #type_generics {
+				#builder {
+					#(#idents: ::core::option::Option::None,)*
+				}
+			}
+		}
+
+		#[automatically_derived]
+		impl #impl_generics #builder #type_generics #where_clause {
+			#(
+				#[doc = concat!("Sets the `", #names, "` field.")]
+				pub fn #idents(&mut self, #idents: #types) -> &mut Self {
+					self.#idents = ::core::option::Option::Some(#idents);
+
+					self
+				}
+			)*
+
+			#[doc = concat!("Builds the [`", stringify!(#ident), "`].")]
+			#[doc = ""]
+			#[doc = "# Errors"]
+			#[doc = concat!(
+				"Returns a [`",
+				stringify!(#error),
+				"`] if any field was not set.",
+			)]
+			pub fn build(self) -> ::core::result::Result<#ident #type_generics, #error> {
+				::core::result::Result::Ok(#ident {
+					#(
+						#idents: match self.#idents {
+							::core::option::Option::Some(#idents) => #idents,
+							::core::option::Option::None => {
+								return ::core::result::Result::Err(#error { field: #names });
+							},
+						},
+					)*
+				})
+			}
+		}
+	)
+	.into()
+}
+
 #[proc_macro_derive(Wrap)]
 pub fn derive_wrap(item: TokenStream) -> TokenStream {
 	let item = parse_macro_input!(item as DeriveInput);
@@ -200,7 +403,10 @@ pub fn derive_constant_x11_size(item: TokenStream) -> TokenStream {
 	// TODO: add generic bounds
 	let (impl_generics, type_generics, where_clause) = item.generics.split_for_impl();
 
-	let x11_sizes = derive_constant_x11_sizes(&item.attrs, &item.data);
+	let x11_sizes = match derive_constant_x11_sizes(&item.attrs, &item.data) {
+		Ok(x11_sizes) => x11_sizes,
+		Err(error) => return error.to_compile_error().into(),
+	};
 
 	quote!(
 		#[automatically_derived]
@@ -326,7 +532,8 @@ pub fn derive_constant_x11_size(item: TokenStream) -> TokenStream {
 /// > &nbsp;&nbsp; | _ContextAttribute_[^attr-once]\
 /// > &nbsp;&nbsp; | _MetabyteAttribute_[^attr-once]\
 /// > &nbsp;&nbsp; | _SequenceAttribute_[^attr-once][^sequence]\
-/// > &nbsp;&nbsp; | _HideAttribute_[^attr-once] )<sup>\*</sup>\
+/// > &nbsp;&nbsp; | _HideAttribute_[^attr-once]\
+/// > &nbsp;&nbsp; | _OffsetAttribute_[^attr-once][^offset] )<sup>\*</sup>\
 /// > &nbsp;&nbsp; [_Visibility_]<sup>?</sup> [IDENTIFIER] `:` [_Type_]
 /// >
 /// > _UnnamedField_ :\
@@ -334,7 +541,8 @@ pub fn derive_constant_x11_size(item: TokenStream) -> TokenStream {
 /// > &nbsp;&nbsp; | _ContextAttribute_[^attr-once]\
 /// > &nbsp;&nbsp; | _MetabyteAttribute_[^attr-once]\
 /// > &nbsp;&nbsp; | _SequenceAttribute_[^attr-once][^sequence]\
-/// > &nbsp;&nbsp; | _HideAttribute_[^attr-once] )<sup>\*</sup>\
+/// > &nbsp;&nbsp; | _HideAttribute_[^attr-once]\
+/// > &nbsp;&nbsp; | _OffsetAttribute_[^attr-once][^offset] )<sup>\*</sup>\
 /// > &nbsp;&nbsp; [_Visibility_]<sup>?</sup> [_Type_]
 /// >
 /// > _LetElement_ :\
@@ -358,6 +566,9 @@ pub fn derive_constant_x11_size(item: TokenStream) -> TokenStream {
 /// > [^sequence]: *SequenceAttribute*s may only be used on fields in replies
 /// > and events.
 /// >
+/// > [^offset]: *OffsetAttribute*s may not be used on fields which also have
+/// > a *MetabyteAttribute* or *SequenceAttribute*.
+/// >
 /// > _ContextAttribute_ :\
 /// > &nbsp;&nbsp; `#` `[` `context` _Context_ `]`
 /// >
@@ -373,6 +584,15 @@ pub fn derive_constant_x11_size(item: TokenStream) -> TokenStream {
 /// > _SequenceAttribute_ :\
 /// > &nbsp;&nbsp; `#` `[` `sequence` `]`
 /// >
+/// > _OffsetAttribute_[^offset-bytes] :\
+/// > &nbsp;&nbsp; `#` `[` `offset` `(` [_Expression_] `)` `]`
+/// >
+/// > [^offset-bytes]: The offset is a `usize` measured in bytes from the
+/// > start of the message body, after the header: a field directly after a
+/// > request's 4-byte header is at offset `0`. Unused bytes are written or
+/// > skipped until the offset is reached; if previous elements extend past it,
+/// > a `MisplacedField` error is returned.
+/// >
 /// > _HideAttribute_ :\
 /// > &nbsp;&nbsp; `#` `[` `hide` `(` _HiddenTraits_ `)` `]`
 /// >
@@ -60,10 +60,10 @@ impl SourceArgs {
 	pub fn formatted_tokens(&self, tokens: &mut TokenStream2) {
 		if let Some((_, definition_type)) = &self.remaining_arg {
 			match definition_type {
-				DefinitionType::Request => quote!((((length - 1) as usize) * 4) - size,),
-				DefinitionType::Reply => {
-					quote!(((length as usize) * 4) + (32 - 8) - size,)
-				},
+				// `size` includes the header, so the total length of the message
+				// (including its header) is used.
+				DefinitionType::Request => quote!(((length as usize) * 4) - size,),
+				DefinitionType::Reply => quote!(((length as usize) * 4) + 32 - size,),
 				_ => unreachable!(),
 			}
 			.to_tokens(tokens);
@@ -0,0 +1,15 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::derive_xrb;
+
+derive_xrb! {
+	#[derive(X11Size, ConstantX11Size)]
+	enum Toggle {
+		Off,
+		On,
+	}
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::unwrap;
+
+#[derive(unwrap)]
+union Bits {
+	signed: i32,
+	unsigned: u32,
+}
+
+fn main() {}
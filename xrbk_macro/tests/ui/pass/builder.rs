@@ -0,0 +1,36 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::builder;
+
+#[derive(Debug, PartialEq, Eq, builder)]
+struct Labelled {
+	x: i16,
+	width: u16,
+	label: u32,
+}
+
+fn main() {
+	// Fields can be set in any order.
+	let mut builder = Labelled::builder();
+	builder.label(7).x(-1).width(2);
+
+	assert_eq!(
+		builder.build(),
+		Ok(Labelled {
+			x: -1,
+			width: 2,
+			label: 7,
+		}),
+	);
+
+	// The first field which was not set is reported.
+	let mut builder = Labelled::builder();
+	builder.x(-1);
+
+	let error = builder.build().unwrap_err();
+
+	assert_eq!(error, LabelledBuilderError { field: "width" });
+	assert_eq!(error.to_string(), "field `width` was not set");
+}
@@ -0,0 +1,35 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk::ConstantX11Size as _;
+use xrbk_macro::{ConstantX11Size, X11Size};
+
+#[derive(X11Size, ConstantX11Size)]
+enum Placeholder {
+	Only,
+}
+
+#[derive(X11Size, ConstantX11Size)]
+enum Wrapper {
+	Only(u16, i32),
+}
+
+#[derive(X11Size, ConstantX11Size)]
+#[no_discrim]
+enum Undiscriminated {
+	Only { value: u32 },
+}
+
+// A single-variant enum is its discriminant followed by its fields.
+const _: () = assert!(Placeholder::X11_SIZE == 1);
+const _: () = assert!(Wrapper::X11_SIZE == 7);
+const _: () = assert!(Undiscriminated::X11_SIZE == 4);
+
+fn main() {
+	let _ = (
+		Placeholder::Only,
+		Wrapper::Only(1, 2),
+		Undiscriminated::Only { value: 3 },
+	);
+}
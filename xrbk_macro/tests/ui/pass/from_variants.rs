@@ -0,0 +1,27 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::from_variants;
+
+#[derive(Debug, PartialEq, Eq, from_variants)]
+enum Time {
+	CurrentTime,
+	Specific(u32),
+}
+
+#[derive(Debug, PartialEq, Eq, from_variants)]
+enum Inheritable<T> {
+	CopyFromParent,
+	Uninherited(T),
+}
+
+fn main() {
+	assert_eq!(Time::from(42), Time::Specific(42));
+
+	let value: Inheritable<u8> = 7.into();
+	assert_eq!(value, Inheritable::Uninherited(7));
+
+	// Sentinel variants are left alone.
+	let _ = (Time::CurrentTime, Inheritable::<u8>::CopyFromParent);
+}
@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk::{ConstantX11Size, Readable, Writable, X11Size};
+use xrbk_macro::derive_xrb;
+
+derive_xrb! {
+	#[derive(Debug, PartialEq, Eq, X11Size, ConstantX11Size, Readable, Writable)]
+	struct Tagged {
+		pub first: u8,
+
+		#[hide(Writable)]
+		pub label: u32,
+
+		pub second: u16,
+	}
+}
+
+fn main() {
+	let tagged = Tagged {
+		first: 1,
+		label: 0xffff_ffff,
+		second: 2,
+	};
+
+	// The `label` is neither written nor counted in the size.
+	let mut bytes = vec![];
+	tagged.write_to(&mut bytes).unwrap();
+
+	assert_eq!(bytes, [1, 0, 2]);
+	assert_eq!(tagged.x11_size(), 3);
+	assert_eq!(Tagged::X11_SIZE, 3);
+
+	// Nor is it read: it is initialized with its `Default` value, and the
+	// `second` field is read straight after the `first`.
+	assert_eq!(
+		Tagged::read_from(&mut &bytes[..]).unwrap(),
+		Tagged {
+			label: 0,
+			..tagged
+		},
+	);
+}
@@ -0,0 +1,23 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use xrbk_macro::unwrap;
+
+#[derive(Debug, PartialEq, Eq, unwrap)]
+enum Target {
+	Window(u32),
+	CursorRoot,
+}
+
+fn main() {
+	assert_eq!(Target::Window(0x0040_0001).unwrap_window(), 0x0040_0001);
+	Target::CursorRoot.unwrap_cursor_root();
+
+	// Unwrapping the wrong variant panics, like `Option::unwrap`. The panic
+	// message is expected, so it isn't printed.
+	std::panic::set_hook(Box::new(|_| {}));
+	let result = std::panic::catch_unwind(|| Target::CursorRoot.unwrap_window());
+
+	assert!(result.is_err());
+}